@@ -1,6 +1,9 @@
 //! Example demonstrating how to derive the trait `Visualize` for a struct, and use it to generate
 //! a DOT file.
 
+// the point of this example is to cover every supported type, however silly its use may be
+#![allow(clippy::box_collection, clippy::borrowed_box)]
+
 use std::error::Error;
 use std::fs::File;
 
@@ -6,9 +6,53 @@ pub struct Address(String);
 
 impl Address {
     /// Create a new address struct with the address referenced by the input t
-    pub fn new<T>(t: &T) -> Self {
-        let ptr: *const _ = t;
-        Self(format!("{:?}", ptr))
+    pub fn new<T>(t: &T) -> Self
+    where
+        T: ?Sized,
+    {
+        Self::from_ptr(t)
+    }
+
+    /// Create a new address struct with the address of ptr
+    ///
+    /// Only the address is kept, without the length or vtable of a pointer to unsized data.
+    pub(crate) fn from_ptr<T>(ptr: *const T) -> Self
+    where
+        T: ?Sized,
+    {
+        Self(format!("{:?}", ptr.cast::<u8>()))
+    }
+
+    /// Create an address naming data computed from the data at this address
+    pub(crate) fn computed(&self, label: &str) -> Self {
+        Self(self.render_port(label))
+    }
+
+    /// The numeric value of this address, unless it names computed data
+    pub(crate) fn value(&self) -> Option<usize> {
+        usize::from_str_radix(self.0.strip_prefix("0x")?, 16).ok()
+    }
+
+    /// Move this address, or the address computed data is named after, from inside the size bytes
+    /// at from to the same offset inside the data at to
+    pub(crate) fn relocated(&self, from: &Address, size: usize, to: &Address) -> Self {
+        let (base, port) = match self.0.split_once(PORT_SEPARATOR) {
+            Some((base, port)) => (Self(base.into()), Some(port)),
+            None => (self.clone(), None),
+        };
+        let relocated = match (base.value(), from.value(), to.value()) {
+            (Some(base), Some(from), Some(to)) => match base.checked_sub(from) {
+                Some(offset) if offset == 0 || offset < size => {
+                    Self::from_ptr((to + offset) as *const u8)
+                }
+                _ => return self.clone(),
+            },
+            _ => return self.clone(),
+        };
+        match port {
+            Some(port) => Self(relocated.render_port(port)),
+            None => relocated,
+        }
     }
 
     fn render_port(&self, suffix: &str) -> String {
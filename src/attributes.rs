@@ -0,0 +1,137 @@
+use crate::util;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The direction a [Graph] is laid out in, from the data at the root of the nodes to the data
+/// they reference
+///
+/// [Graph]: crate::Graph
+pub enum RankDir {
+    /// Top to bottom, the default
+    TopToBottom,
+    /// Left to right
+    LeftToRight,
+    /// Bottom to top
+    BottomToTop,
+    /// Right to left
+    RightToLeft,
+}
+
+impl RankDir {
+    /// The value of the DOT `rankdir` attribute for this direction
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            RankDir::TopToBottom => "TB",
+            RankDir::LeftToRight => "LR",
+            RankDir::BottomToTop => "BT",
+            RankDir::RightToLeft => "RL",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How the edges of a [Graph] are drawn
+///
+/// [Graph]: crate::Graph
+pub enum Splines {
+    /// No edges at all
+    None,
+    /// Straight lines
+    Line,
+    /// Straight line segments
+    Polyline,
+    /// Curves
+    Curved,
+    /// Horizontal and vertical line segments
+    Ortho,
+    /// Splines routed around nodes, the default
+    Spline,
+}
+
+impl Splines {
+    /// The value of the DOT `splines` attribute for this way of drawing edges
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Splines::None => "none",
+            Splines::Line => "line",
+            Splines::Polyline => "polyline",
+            Splines::Curved => "curved",
+            Splines::Ortho => "ortho",
+            Splines::Spline => "spline",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which edges of each node of a [Graph] keep the order they were added in
+///
+/// [Graph]: crate::Graph
+pub enum EdgeOrdering {
+    /// The edges leaving each node, which keeps the left and right children of a binary tree on
+    /// the left and right
+    Out,
+    /// The edges entering each node
+    In,
+}
+
+impl EdgeOrdering {
+    /// The value of the DOT `ordering` attribute for these edges
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            EdgeOrdering::Out => "out",
+            EdgeOrdering::In => "in",
+        }
+    }
+}
+
+/// Set the DOT attribute key to value, replacing its previous value
+pub(crate) fn set(attributes: &mut Vec<(String, String)>, key: String, value: String) {
+    match attributes.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, v)) => *v = value,
+        None => attributes.push((key, value)),
+    }
+}
+
+/// Create a DOT attribute statement, like `node [shape="plaintext"]`, on its own line, or an empty
+/// string if there are no attributes
+pub(crate) fn render_statement(kind: &str, attributes: &[(String, String)]) -> String {
+    match attributes.len() {
+        0 => String::new(),
+        _ => format!(
+            "  {} {}\n",
+            kind,
+            util::render_attribute_list(
+                attributes
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str()))
+            )
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_set_and_render_statement() {
+        let mut attributes = Vec::new();
+        assert_eq!(render_statement("graph", &attributes), "");
+
+        set(&mut attributes, "rankdir".into(), "LR".into());
+        set(&mut attributes, "label".into(), "say \"hi\"".into());
+        set(&mut attributes, "rankdir".into(), "BT".into());
+        assert_eq!(
+            render_statement("graph", &attributes),
+            "  graph [rankdir=\"BT\", label=\"say \\\"hi\\\"\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_render_statement_escapes_backslashes() {
+        let attributes = vec![("label".into(), r"C:\dir\".into())];
+        assert_eq!(
+            render_statement("graph", &attributes),
+            r#"  graph [label="C:\\dir\\"]"#.to_string() + "\n"
+        );
+    }
+}
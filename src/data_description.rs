@@ -1,8 +1,47 @@
 use crate::address::Address;
+use crate::edge::{Edge, EdgeKind};
 use crate::node::RenderedNode;
+use crate::options;
+use crate::style::Style;
+use crate::theme::CellKind;
 use crate::util;
 use crate::Visualize;
 
+use std::alloc::Layout;
+use std::cell::RefCell;
+
+/// The number of bytes in each row of a hex dump
+const HEX_DUMP_ROW_LENGTH: usize = 16;
+
+/// The prefixes of the types which might be written to while they're borrowed
+const INTERIOR_MUTABILITY_PREFIXES: &[&str] = &["core::cell::", "core::sync::", "std::sync::"];
+
+thread_local! {
+    /// The address and type of every [DataDescription] currently being created on this thread,
+    /// outermost first
+    ///
+    /// A reference to data on this stack is a cycle, so the referenced data must not be described
+    /// again.
+    static DESCRIBING: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Guard for an entry on [DESCRIBING], which pops it when dropped, even while unwinding from a
+/// panic in [Visualize::data]
+struct Describing;
+
+impl Describing {
+    fn push(address: String, type_string: String) -> Self {
+        DESCRIBING.with(|describing| describing.borrow_mut().push((address, type_string)));
+        Describing
+    }
+}
+
+impl Drop for Describing {
+    fn drop(&mut self) {
+        let _ = DESCRIBING.with(|describing| describing.borrow_mut().pop());
+    }
+}
+
 #[derive(Debug, Clone)]
 /// The value of a [Visualize] implementer
 pub enum Value {
@@ -11,7 +50,8 @@ pub enum Value {
     /// The implementer references this data and there will be a graph edge from this reference to the
     /// referenced data
     ///
-    /// The memory address of the referenced data.
+    /// The memory address of the referenced data, and the node rendered for it, which also holds
+    /// the edge drawn to it.
     Referenced(Address, RenderedNode),
 }
 
@@ -28,6 +68,61 @@ impl Value {
     {
         Value::Referenced(address.into(), rendered_node.into())
     }
+
+    /// Create a [Value::Referenced] pointing at t
+    ///
+    /// If t is already being described further up, as happens when following a cycle of
+    /// references such as a parent pointer, only the edge is drawn and t is not described again.
+    pub fn reference_to<T>(t: &T) -> Self
+    where
+        T: ?Sized + Visualize,
+    {
+        let address = Address::new(t);
+        let key = (address.to_string(), util::type_of(t));
+        if DESCRIBING.with(|describing| describing.borrow().contains(&key)) {
+            Value::referenced(address, RenderedNode::default())
+        } else {
+            Value::referenced(address, DataDescription::for_node(t))
+        }
+    }
+
+    /// Set the kind of pointer the edge drawn for a [Value::Referenced] is for, which gives it
+    /// that kind's default style
+    ///
+    /// This has no effect on a [Value::Owned], which has no edge.
+    pub fn with_edge_kind(self, kind: EdgeKind) -> Self {
+        match self {
+            Value::Referenced(address, rendered_node) => {
+                Value::Referenced(address, rendered_node.map_edge(|edge| edge.with_kind(kind)))
+            }
+            owned => owned,
+        }
+    }
+
+    /// Set a DOT attribute on the edge drawn for a [Value::Referenced]
+    ///
+    /// This has no effect on a [Value::Owned], which has no edge.
+    pub fn with_edge_attribute<K, V>(self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        match self {
+            Value::Referenced(address, rendered_node) => Value::Referenced(
+                address,
+                rendered_node.map_edge(|edge| edge.with_attribute(key, value)),
+            ),
+            owned => owned,
+        }
+    }
+
+    /// Get the edge drawn for a [Value::Referenced], or [None] for a [Value::Owned]
+    pub fn edge(&self) -> Option<&Edge> {
+        match self {
+            Value::Referenced(_, rendered_node) => Some(rendered_node.edge()),
+            Value::Owned(_) => None,
+        }
+    }
 }
 
 #[readonly::make]
@@ -59,9 +154,139 @@ pub struct DataDescription {
     ///
     /// These will be rendered as part of this data.
     pub associated_data_descriptions: Option<Vec<DataDescription>>,
+    /// Whether this data is computed from the data that owns it, such as a length or a lock
+    /// state, rather than stored at an address of its own
+    ///
+    /// Computed data is rendered without an address or type.
+    pub computed: bool,
+    /// Whether this data is memory which holds no value, such as the spare capacity of a
+    /// collection
+    ///
+    /// Unused data is rendered greyed out.
+    pub unused: bool,
+    /// The size and alignment of this data, if it is stored at an address of its own
+    pub layout: Option<Layout>,
+    /// The look the type of this data declares for itself
+    pub style: Style,
 }
 
 impl DataDescription {
+    /// Create a [DataDescription] for data at address which has no [Visualize] impl of its own,
+    /// such as the heap buffer of a collection
+    pub(crate) fn new(address: Address, type_string: String) -> Self {
+        Self {
+            address,
+            type_string,
+            ..Default::default()
+        }
+    }
+
+    /// Create a computed [DataDescription] for memory owned by the data at owner_address which
+    /// holds no value, such as the spare capacity of a collection
+    pub(crate) fn unused<T>(owner_address: &Address, label: T, value: Value) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            unused: true,
+            ..Self::computed(owner_address, label, value)
+        }
+    }
+
+    /// Create a [DataDescription] for t as the root of a graph node, which has a hex dump if the
+    /// current [Graph] renders them
+    ///
+    /// [Graph]: crate::Graph
+    pub(crate) fn for_node<T>(t: &T) -> Self
+    where
+        T: ?Sized + Visualize,
+    {
+        let data_description = DataDescription::from(t);
+        match options::current().hex_dumps {
+            true => data_description.with_hex_dump(t),
+            false => data_description,
+        }
+    }
+
+    /// Add rows with a hex and ASCII dump of the bytes of t, which must be the data this
+    /// [DataDescription] describes
+    ///
+    /// Only the bytes of primitives and pointers described inside of t are read, because any other
+    /// byte might be uninitialized padding. The bytes which aren't read are marked with `··`.
+    /// Nothing inside of a [Cell], [Mutex] or similar is read either, because it might be written
+    /// to in the meantime.
+    ///
+    /// [Cell]: std::cell::Cell
+    /// [Mutex]: std::sync::Mutex
+    pub fn with_hex_dump<T>(self, t: &T) -> Self
+    where
+        T: ?Sized + Visualize,
+    {
+        let start = match self.address.value() {
+            Some(start)
+                if self.address.to_string() == Address::new(t).to_string()
+                    && self.type_string == util::type_of(t) =>
+            {
+                start
+            }
+            _ => return self,
+        };
+
+        let mut initialized = vec![false; size_of_val(t)];
+        self.mark_initialized_bytes(start, &mut initialized);
+        let base: *const T = t;
+        let bytes: Vec<_> = initialized
+            .iter()
+            .enumerate()
+            .map(|(offset, initialized)| {
+                // SAFETY: the byte is inside of t, which is borrowed, and part of a primitive or
+                // pointer, so it is initialized and nothing can write to it in the meantime
+                initialized.then(|| unsafe { *(base as *const u8).add(offset) })
+            })
+            .collect();
+
+        let address = self.address.clone();
+        bytes
+            .chunks(HEX_DUMP_ROW_LENGTH)
+            .enumerate()
+            .map(|(row, bytes)| {
+                let offset = row * HEX_DUMP_ROW_LENGTH;
+                DataDescription::computed(
+                    &address,
+                    format!("hex +{}", offset),
+                    Value::Owned(util::render_hex_dump_row(bytes)),
+                )
+            })
+            .fold(self, DataDescription::with_associated_data)
+    }
+
+    /// Mark the bytes of the primitives and pointers described inside of this data, which starts
+    /// start bytes after the data being dumped
+    fn mark_initialized_bytes(&self, start: usize, initialized: &mut [bool]) {
+        if self.computed
+            || INTERIOR_MUTABILITY_PREFIXES
+                .iter()
+                .any(|prefix| self.type_string.starts_with(prefix))
+        {
+            return;
+        }
+
+        if let (Some(address), Some(layout)) = (self.address.value(), self.layout) {
+            if util::is_plain_type(&self.type_string) {
+                if let Some(offset) = address.checked_sub(start) {
+                    if let Some(bytes) = initialized.get_mut(offset..offset + layout.size()) {
+                        bytes.iter_mut().for_each(|byte| *byte = true);
+                    }
+                }
+                return;
+            }
+        }
+
+        for associated_data in self.associated_data_descriptions.iter().flatten() {
+            associated_data.mark_initialized_bytes(start, initialized);
+        }
+    }
+
     /// Add a label to this node
     ///
     /// Labels are generally optional but can be helpful for named structured data, like the
@@ -76,19 +301,103 @@ impl DataDescription {
         }
     }
 
+    /// Move the addresses of this data and the data it owns from the size bytes at from to to
+    pub(crate) fn relocated(self, from: &Address, size: usize, to: &Address) -> Self {
+        Self {
+            address: self.address.relocated(from, size, to),
+            associated_data_descriptions: self.associated_data_descriptions.map(|descriptions| {
+                descriptions
+                    .into_iter()
+                    .map(|description| description.relocated(from, size, to))
+                    .collect()
+            }),
+            ..self
+        }
+    }
+
+    /// Add a [DataDescription] to the data owned by this data
+    pub(crate) fn with_associated_data(mut self, data_description: DataDescription) -> Self {
+        self.associated_data_descriptions
+            .get_or_insert_with(Vec::new)
+            .push(data_description);
+        self
+    }
+
+    /// A rendering of this data's value that doesn't depend on where it is in memory, following
+    /// references to the value of the data they reference
+    ///
+    /// Data without a value of its own is rendered as the values of the data it owns, like
+    /// `(1, a)`, but references to such data are not followed. This is used to order the elements
+    /// of unordered collections.
+    pub(crate) fn summary(&self) -> Option<String> {
+        match &self.value {
+            Some(Value::Owned(data)) => Some(data.clone()),
+            Some(Value::Referenced(_, rendered_node)) => rendered_node.summary().map(String::from),
+            None => {
+                let values = self
+                    .associated_data_descriptions
+                    .as_ref()?
+                    .iter()
+                    .filter(|data_description| !data_description.computed)
+                    .map(DataDescription::summary)
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("({})", values.join(", ")))
+            }
+        }
+    }
+
+    /// Create a [DataDescription] for data computed from the data at owner_address, rather than
+    /// stored at an address of its own
+    ///
+    /// The label must be unique among the data owned by owner_address, because it is used to name
+    /// the ports of this data.
+    pub fn computed<T>(owner_address: &Address, label: T, value: Value) -> Self
+    where
+        T: Into<String>,
+    {
+        let label_string = label.into();
+        Self {
+            address: owner_address.computed(&label_string),
+            label_string: Some(label_string),
+            value: Some(value),
+            computed: true,
+            ..Default::default()
+        }
+    }
+
     /// Create the DOT code to make an arrow from this node to another node
     ///
     /// The other node will need to be added to the graph separately from this call.
     ///
     /// The node_root_address is the name of the top level data this [DataDescription] lives inside of.
-    fn render_reference(&self, node_root_address: &Address) -> Option<String> {
+    /// The position is the index of this data among the data owned by its owner, which is the
+    /// edge label if the current [Graph] renders edge labels and this data has no label.
+    ///
+    /// [Graph]: crate::Graph
+    fn render_reference(
+        &self,
+        node_root_address: &Address,
+        position: Option<usize>,
+    ) -> Option<String> {
         if let Some(Value::Referenced(target_address, target_rendered_node)) = &self.value {
+            let edge = target_rendered_node.edge();
+            let label = match &self.label_string {
+                Some(label_string) => Some(label_string.clone()),
+                None => position.map(|position| position.to_string()),
+            };
+            let edge = match label {
+                Some(label) if options::current().edge_labels && !edge.has_attribute("label") => {
+                    edge.clone().with_attribute("label", label)
+                }
+                _ => edge.clone(),
+            };
             Some(format!(
-                "\"{}\":\"{}\" -> \"{}\":\"{}\"\n{}\n",
+                "\"{}\":\"{}\" -> \"{}\":\"{}\"{}\n{}\n",
                 node_root_address,
                 self.address.render_value_port(),
                 target_address,
                 target_address.render_address_port(),
+                edge.render_attributes(),
                 target_rendered_node
             ))
         } else {
@@ -101,27 +410,58 @@ impl DataDescription {
     ///
     /// The referenced nodes must be added to the graph separately.
     pub fn render_references(&self, node_root_address: &Address) -> String {
+        self.render_references_at(node_root_address, None)
+    }
+
+    /// Create the DOT code to make all arrows from data owned by this node to the data they
+    /// reference, where this data is at position among the data owned by its owner
+    fn render_references_at(&self, node_root_address: &Address, position: Option<usize>) -> String {
         let this_reference = self
-            .render_reference(node_root_address)
-            .unwrap_or_else(String::new);
+            .render_reference(node_root_address, position)
+            .unwrap_or_default();
 
         match &self.associated_data_descriptions {
             Some(associated_data_descriptions) => associated_data_descriptions
                 .iter()
-                .fold(this_reference, |acc, associated_data| {
-                    acc + &associated_data.render_references(node_root_address)
+                .enumerate()
+                .fold(this_reference, |acc, (position, associated_data)| {
+                    acc + &associated_data.render_references_at(node_root_address, Some(position))
                 }),
             None => this_reference,
         }
     }
 
+    /// The attributes of the table data (<TD>) of this data, including the leading space
+    fn render_table_data_attributes(&self, cell: CellKind) -> String {
+        options::current().theme.render_cell_attributes(
+            cell,
+            &self.type_string,
+            self.unused,
+            self.style.color.as_deref(),
+        )
+    }
+
+    /// The contents of a kind of table data (<TD>) of this data, in the font color of that cell
+    fn render_table_data_contents(&self, cell: CellKind, contents: String) -> String {
+        options::current().theme.render_cell_contents(
+            cell,
+            &self.type_string,
+            self.unused,
+            contents,
+        )
+    }
+
     fn render_label_table_data(&self) -> String {
         match &self.label_string {
             Some(label_string) => {
                 format!(
-                    r#"<TD PORT="{}">{}</TD>"#,
+                    r#"<TD PORT="{}"{}>{}</TD>"#,
                     self.address.render_label_port(),
-                    label_string
+                    self.render_table_data_attributes(CellKind::Label),
+                    self.render_table_data_contents(
+                        CellKind::Label,
+                        util::html_encode(label_string)
+                    )
                 )
             }
             None => String::new(),
@@ -129,30 +469,84 @@ impl DataDescription {
     }
 
     fn render_hex_address_table_data(&self) -> String {
+        if self.computed {
+            return String::new();
+        }
+
         format!(
-            r#"<TD PORT="{}"><I>{}</I></TD>"#,
+            r#"<TD PORT="{}"{}>{}</TD>"#,
             self.address.render_address_port(),
-            self.address,
+            self.render_table_data_attributes(CellKind::Address),
+            self.render_table_data_contents(CellKind::Address, format!("<I>{}</I>", self.address)),
         )
     }
 
     fn render_type_table_data(&self) -> String {
+        if self.computed {
+            return String::new();
+        }
+
         format!(
-            r#"<TD PORT="{}"><B>{}</B></TD>"#,
+            r#"<TD PORT="{}"{}>{}</TD>"#,
             self.address.render_type_port(),
-            util::html_encode(&self.type_string)
+            self.render_table_data_attributes(CellKind::Type),
+            self.render_table_data_contents(CellKind::Type, self.render_type_contents())
         )
     }
 
+    /// The contents of the type table data (<TD>), which is the type, or the title the type
+    /// declares for itself, after the icon it declares for itself
+    fn render_type_contents(&self) -> String {
+        let title = self.style.title.as_ref().unwrap_or(&self.type_string);
+        let title = format!("<B>{}</B>", util::html_encode(title));
+        match &self.style.icon {
+            Some(icon) => format!("{} {}", util::html_encode(icon), title),
+            None => title,
+        }
+    }
+
+    /// Render the table data (<TD>) showing where this data sits in memory, in memory layout mode
+    ///
+    /// The offset is relative to the data that owns this data, if it lies inside of it.
+    fn render_layout_table_data(&self, offset: Option<usize>) -> String {
+        if !options::current().memory_layout {
+            return String::new();
+        }
+
+        let offset = offset.map(|offset| format!("offset {}", offset));
+        let layout = self
+            .layout
+            .map(|layout| format!("size {}, align {}", layout.size(), layout.align()));
+        match (offset, layout) {
+            (None, None) => String::new(),
+            (offset, layout) => format!(
+                r#"<TD{}>{}</TD>"#,
+                self.render_table_data_attributes(CellKind::Value),
+                self.render_table_data_contents(
+                    CellKind::Value,
+                    offset
+                        .into_iter()
+                        .chain(layout)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            ),
+        }
+    }
+
     fn render_value_table_data(&self) -> String {
         match &self.value {
             Some(value) => format!(
-                r#"<TD PORT="{}">{}</TD>"#,
+                r#"<TD PORT="{}"{}>{}</TD>"#,
                 self.address.render_value_port(),
-                match value {
-                    Value::Owned(data) => util::html_encode(data),
-                    Value::Referenced(_, _) => String::new(),
-                }
+                self.render_table_data_attributes(CellKind::Value),
+                self.render_table_data_contents(
+                    CellKind::Value,
+                    match value {
+                        Value::Owned(data) => util::html_encode(data),
+                        Value::Referenced(..) => String::new(),
+                    }
+                )
             ),
             None => String::new(),
         }
@@ -163,23 +557,101 @@ impl DataDescription {
             Some(associated_data_descriptions) => format!(
                 r#"<TD PORT="{}">{}</TD>"#,
                 self.address.render_associated_data_port(),
-                util::render_table(
-                    associated_data_descriptions
-                        .iter()
-                        .map(DataDescription::render_table_row),
-                )
+                util::render_table(std::iter::once(
+                    self.render_associated_data_rows(associated_data_descriptions)
+                ))
             ),
             None => String::new(),
         }
     }
 
+    fn render_associated_data_rows(
+        &self,
+        associated_data_descriptions: &[DataDescription],
+    ) -> String {
+        options::current()
+            .memory_layout
+            .then(|| self.render_associated_data_rows_by_offset(associated_data_descriptions))
+            .flatten()
+            .unwrap_or_else(|| {
+                associated_data_descriptions
+                    .iter()
+                    .map(DataDescription::render_table_row)
+                    .collect()
+            })
+    }
+
+    /// Render the data owned by this data in the order it sits in memory, with rows for the gaps
+    /// between it, or [None] if any of it lies outside of this data
+    ///
+    /// Computed data doesn't sit in memory, so it is rendered after everything else.
+    fn render_associated_data_rows_by_offset(
+        &self,
+        associated_data_descriptions: &[DataDescription],
+    ) -> Option<String> {
+        let start = self.address.value()?;
+        let size = self.layout?.size();
+        let mut fields = associated_data_descriptions
+            .iter()
+            .filter(|data_description| !data_description.computed)
+            .map(|data_description| {
+                let offset = data_description.address.value()?.checked_sub(start)?;
+                let end = offset + data_description.layout?.size();
+                (end <= size).then_some((offset, end, data_description))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        fields.sort_by_key(|(offset, _, _)| *offset);
+
+        // the gaps in a struct or tuple are padding, but other data may keep undescribed state in
+        // its gaps, such as a lock's state or an enum's tag
+        let gap_label = match self.value {
+            None => "padding",
+            Some(_) => "undescribed",
+        };
+        let render_gap = |offset: usize, end: usize| {
+            DataDescription::unused(
+                &self.address,
+                format!("{}-{}", gap_label, offset),
+                Value::Owned(format!("{} bytes", end - offset)),
+            )
+            .with_label(gap_label)
+            .render_table_row_at(Some(offset))
+        };
+
+        let mut rows = String::new();
+        let mut covered = 0;
+        for (offset, end, field) in fields {
+            if offset > covered {
+                rows += &render_gap(covered, offset);
+            }
+            rows += &field.render_table_row_at(Some(offset));
+            covered = covered.max(end);
+        }
+        if size > covered {
+            rows += &render_gap(covered, size);
+        }
+
+        Some(
+            associated_data_descriptions
+                .iter()
+                .filter(|data_description| data_description.computed)
+                .fold(rows, |rows, computed| rows + &computed.render_table_row()),
+        )
+    }
+
     /// Create the HTML table row for this data
     pub fn render_table_row(&self) -> String {
+        self.render_table_row_at(None)
+    }
+
+    /// Create the HTML table row for this data, which sits at offset inside the data that owns it
+    fn render_table_row_at(&self, offset: Option<usize>) -> String {
         format!(
-            "<TR>{}{}{}{}{}</TR>",
+            "<TR>{}{}{}{}{}{}</TR>",
             self.render_label_table_data(),
             self.render_hex_address_table_data(),
             self.render_type_table_data(),
+            self.render_layout_table_data(offset),
             self.render_value_table_data(),
             self.render_associated_data_table(),
         )
@@ -188,15 +660,27 @@ impl DataDescription {
 
 impl<T> From<&T> for DataDescription
 where
-    T: Visualize,
+    T: ?Sized + Visualize,
 {
     fn from(t: &T) -> Self {
+        let address = Address::new(t);
+        let type_string = util::type_of(t);
+
+        let describing = Describing::push(address.to_string(), type_string.clone());
+        let value = t.data();
+        let associated_data_descriptions = t.associated_data();
+        drop(describing);
+
         Self {
             label_string: None,
-            address: Address::new(t),
-            type_string: util::type_of(t),
-            value: t.data(),
-            associated_data_descriptions: t.associated_data(),
+            address,
+            type_string,
+            value,
+            associated_data_descriptions,
+            computed: false,
+            unused: false,
+            layout: Some(Layout::for_value(t)),
+            style: t.style(),
         }
     }
 }
@@ -231,6 +715,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         assert_eq!(data_description.render_table_row(), "<TR><TD PORT=\"0x12345678-label\">my_label</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>u8</B></TD><TD PORT=\"0x12345678-value\">145</TD></TR>");
@@ -250,6 +735,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         assert_eq!(data_description.render_table_row(), "<TR><TD PORT=\"0x12345678-label\">my_label</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>foo::bar::Enum</B></TD><TD PORT=\"0x12345678-value\">MyEnumVariant</TD></TR>");
@@ -269,6 +755,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
@@ -283,6 +770,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
@@ -297,6 +785,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         assert_eq!(data_description.render_table_row(), "<TR><TD PORT=\"0x12345678-label\">my_label</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>foo::bar::Enum</B></TD><TD PORT=\"0x12345678-value\">MyEnumVariant</TD><TD PORT=\"0x12345678-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>u8</B></TD><TD PORT=\"0x12345678-value\">178</TD></TR><TR><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>alloc::string::String</B></TD><TD PORT=\"0x12345678-value\">abcdefghi</TD></TR></TABLE></TD></TR>");
@@ -316,6 +805,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
@@ -330,6 +820,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
@@ -344,6 +835,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         assert_eq!(data_description.render_table_row(), "<TR><TD PORT=\"0x12345678-label\">my_label</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>foo::bar::Enum</B></TD><TD PORT=\"0x12345678-value\">MyEnumVariant</TD><TD PORT=\"0x12345678-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"0x12345678-label\">my_u8</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>u8</B></TD><TD PORT=\"0x12345678-value\">178</TD></TR><TR><TD PORT=\"0x12345678-label\">my_string</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>alloc::string::String</B></TD><TD PORT=\"0x12345678-value\">abcdefghi</TD></TR></TABLE></TD></TR>");
@@ -363,6 +855,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
@@ -377,6 +870,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
@@ -391,6 +885,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         assert_eq!(data_description.render_table_row(), "<TR><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>foo::bar::Struct</B></TD><TD PORT=\"0x12345678-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"0x12345678-label\">my_u8</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>u8</B></TD><TD PORT=\"0x12345678-value\">178</TD></TR><TR><TD PORT=\"0x12345678-label\">my_string</TD><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>alloc::string::String</B></TD><TD PORT=\"0x12345678-value\">abcdefghi</TD></TR></TABLE></TD></TR>");
@@ -401,7 +896,7 @@ mod test {
         let address = Address::from("0x12345678");
         let label_string = None;
         let type_string = String::from("&foo::bar::Struct");
-        let value = Some(Value::Referenced(
+        let value = Some(Value::referenced(
             Address::from("0xcafebaee"),
             RenderedNode::from(String::from("this is unchecked")),
         ));
@@ -413,6 +908,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         assert_eq!(data_description.render_table_row(), "<TR><TD PORT=\"0x12345678-address\"><I>0x12345678</I></TD><TD PORT=\"0x12345678-type\"><B>&amp;foo::bar::Struct</B></TD><TD PORT=\"0x12345678-value\"></TD></TR>");
@@ -425,7 +921,7 @@ mod test {
         let address = Address::from("0x12345678");
         let label_string = Some(String::from("my_u8_ref"));
         let type_string = String::from("u8");
-        let value = Some(Value::Referenced(
+        let value = Some(Value::referenced(
             Address::from("ref1"),
             RenderedNode::from(String::from(referenced_rendered_node_content)),
         ));
@@ -437,12 +933,13 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
         let label_string = Some(String::from("my_string_ref"));
         let type_string = String::from("alloc::string::String");
-        let value = Some(Value::Referenced(
+        let value = Some(Value::referenced(
             Address::from("ref2"),
             RenderedNode::from(String::from(referenced_rendered_node_content)),
         ));
@@ -454,6 +951,7 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         let address = Address::from("0x12345678");
@@ -468,8 +966,105 @@ mod test {
             type_string,
             value,
             associated_data_descriptions,
+            ..Default::default()
         };
 
         assert_eq!(data_description.render_references(&Address::from("root-node-name")), format!( "\"root-node-name\":\"0x12345678-value\" -> \"ref1\":\"ref1-address\"\n{0}\n\"root-node-name\":\"0x12345678-value\" -> \"ref2\":\"ref2-address\"\n{0}\n", referenced_rendered_node_content));
     }
+
+    #[test]
+    fn test_render_memory_layout() {
+        #[repr(C)]
+        struct Padded {
+            a: u8,
+            b: u32,
+        }
+
+        // list the fields out of order, as if the compiler had reordered them
+        impl Visualize for Padded {
+            fn associated_data(&self) -> Option<Vec<DataDescription>> {
+                Some(vec![
+                    DataDescription::from(&self.b).with_label("b"),
+                    DataDescription::from(&self.a).with_label("a"),
+                ])
+            }
+        }
+
+        let rendered = crate::Graph::new()
+            .set_memory_layout(true)
+            .add_node(&Padded { a: 1, b: 2 })
+            .render();
+        let a_row = rendered
+            .find("<TD>offset 0, size 1, align 1</TD><TD PORT=")
+            .unwrap();
+        let padding_row = rendered
+            .find(r#">padding</TD><TD BGCOLOR="lightgrey">offset 1</TD><TD PORT="#)
+            .unwrap();
+        let b_row = rendered
+            .find("<TD>offset 4, size 4, align 4</TD><TD PORT=")
+            .unwrap();
+        assert!(rendered.contains("<TD>size 8, align 4</TD>"));
+        assert!(rendered.contains(">3 bytes</TD>"));
+        assert!(a_row < padding_row && padding_row < b_row);
+    }
+
+    #[test]
+    fn test_describing_popped_on_panic() {
+        struct Panics;
+
+        impl Visualize for Panics {
+            fn data(&self) -> Option<Value> {
+                panic!("describing Panics");
+            }
+        }
+
+        assert!(std::panic::catch_unwind(|| DataDescription::from(&Panics)).is_err());
+        assert!(DESCRIBING.with(|describing| describing.borrow().is_empty()));
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        #[repr(C)]
+        struct Padded {
+            a: u8,
+            b: u32,
+        }
+
+        impl Visualize for Padded {
+            fn associated_data(&self) -> Option<Vec<DataDescription>> {
+                Some(vec![
+                    DataDescription::from(&self.a).with_label("a"),
+                    DataDescription::from(&self.b).with_label("b"),
+                ])
+            }
+        }
+
+        let padded = Padded {
+            a: b'A',
+            b: u32::from_ne_bytes([1, 2, 3, 4]),
+        };
+        let data_description = DataDescription::from(&padded).with_hex_dump(&padded);
+        let hex_dump = data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(hex_dump.label_string.as_deref(), Some("hex +0"));
+        assert_eq!(
+            hex_dump.summary().as_deref(),
+            Some("41 ·· ·· ·· 01 02 03 04 |A···....|")
+        );
+
+        // a hex dump of some other data is ignored
+        let data_description = DataDescription::from(&padded).with_hex_dump(&padded.a);
+        assert_eq!(
+            data_description
+                .associated_data_descriptions
+                .as_ref()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
 }
@@ -0,0 +1,262 @@
+use crate::attributes;
+use crate::options;
+use crate::util;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The kind of pointer an edge is drawn for, which decides its default style
+pub enum EdgeKind {
+    /// A shared borrow, `&T`, drawn as a plain arrow
+    SharedBorrow,
+    /// A unique borrow, `&mut T`, drawn bold
+    UniqueBorrow,
+    /// A pointer owning the data it points to, like a [Box] or the buffer of a [Vec], drawn with
+    /// a filled diamond at the owner
+    Owning,
+    /// A pointer sharing ownership of the data it points to, like an [std::rc::Rc] or
+    /// [std::sync::Arc], drawn with a hollow diamond at the owner
+    SharedOwnership,
+    /// A pointer which doesn't keep the data it points to alive, like an [std::rc::Weak], drawn
+    /// dashed and without pulling the data into a lower rank
+    Weak,
+    /// A raw pointer, drawn dotted
+    Raw,
+    /// An index into a collection, drawn grey with a hollow arrowhead
+    Index,
+}
+
+impl EdgeKind {
+    /// Every kind, in the order they are listed in the legend
+    const ALL: [EdgeKind; 7] = [
+        EdgeKind::SharedBorrow,
+        EdgeKind::UniqueBorrow,
+        EdgeKind::Owning,
+        EdgeKind::SharedOwnership,
+        EdgeKind::Weak,
+        EdgeKind::Raw,
+        EdgeKind::Index,
+    ];
+
+    /// The DOT attributes every edge of this kind has, unless they are overridden
+    fn default_attributes(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            EdgeKind::SharedBorrow => &[],
+            EdgeKind::UniqueBorrow => &[("style", "bold")],
+            EdgeKind::Owning => &[("dir", "both"), ("arrowtail", "diamond")],
+            EdgeKind::SharedOwnership => &[("dir", "both"), ("arrowtail", "odiamond")],
+            EdgeKind::Weak => &[("style", "dashed"), ("constraint", "false")],
+            EdgeKind::Raw => &[("style", "dotted")],
+            EdgeKind::Index => &[("color", "grey40"), ("arrowhead", "empty")],
+        }
+    }
+
+    /// The pointer types of this kind, as they are described in the legend
+    fn description(self) -> &'static str {
+        match self {
+            EdgeKind::SharedBorrow => "&amp;T shared borrow",
+            EdgeKind::UniqueBorrow => "&amp;mut T unique borrow",
+            EdgeKind::Owning => "Box&lt;T&gt; owning",
+            EdgeKind::SharedOwnership => "Rc&lt;T&gt; / Arc&lt;T&gt; shared ownership",
+            EdgeKind::Weak => "Weak&lt;T&gt; non-owning",
+            EdgeKind::Raw => "*const T / *mut T raw pointer",
+            EdgeKind::Index => "index",
+        }
+    }
+
+    /// Create the DOT code for a cluster with an example edge of every kind next to a
+    /// description of it
+    pub(crate) fn render_legend() -> String {
+        let rows = EdgeKind::ALL
+            .iter()
+            .enumerate()
+            .map(|(index, kind)| {
+                let edge = Edge::default().with_kind(*kind);
+                format!(
+                    "    \"legend-{0}\" [label=<{1}>];\n    \"legend-{0}-target\" [label=\"\", shape=point];\n    \"legend-{0}\" -> \"legend-{0}-target\"{2}\n",
+                    index,
+                    kind.description(),
+                    edge.render_attributes()
+                )
+            })
+            .collect::<String>();
+        format!(
+            "  subgraph cluster_legend {{\n    label=\"legend\"\n    node [shape=plaintext]\n{}  }}\n",
+            rows
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The edges of a [Graph] an attribute set with [Graph::set_edge_attribute] applies to
+///
+/// An [EdgeKind] converts into the selector for edges of that kind.
+///
+/// [Graph]: crate::Graph
+/// [Graph::set_edge_attribute]: crate::Graph::set_edge_attribute
+pub enum EdgeSelector {
+    /// Every edge
+    All,
+    /// Edges without a kind, like those drawn by [Visualize] implementations which don't set one
+    ///
+    /// [Visualize]: crate::Visualize
+    Unkinded,
+    /// Edges of a kind
+    Kind(EdgeKind),
+}
+
+impl EdgeSelector {
+    /// Whether this selects edges of kind
+    fn matches(self, kind: Option<EdgeKind>) -> bool {
+        match self {
+            EdgeSelector::All => true,
+            EdgeSelector::Unkinded => kind.is_none(),
+            EdgeSelector::Kind(selected) => kind == Some(selected),
+        }
+    }
+}
+
+impl From<EdgeKind> for EdgeSelector {
+    fn from(kind: EdgeKind) -> Self {
+        EdgeSelector::Kind(kind)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// The DOT attributes of the arrow drawn from a reference to the data it references
+pub struct Edge {
+    /// The kind of pointer this edge is drawn for, if it is known
+    kind: Option<EdgeKind>,
+    /// DOT attribute key/value pairs, rendered in insertion order after the default attributes
+    /// of the kind, which they override
+    attributes: Vec<(String, String)>,
+}
+
+impl Edge {
+    /// Set the kind of pointer this edge is drawn for, which gives it that kind's default style
+    pub fn with_kind(self, kind: EdgeKind) -> Self {
+        Self {
+            kind: Some(kind),
+            ..self
+        }
+    }
+
+    /// Set a DOT attribute on this edge, such as `label` or `style`
+    ///
+    /// See the DOT attribute docs for the available attributes:
+    /// <https://graphviz.org/doc/info/attrs.html>
+    pub fn with_attribute<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.attributes.push((key.into(), value.into()));
+        self
+    }
+
+    /// Whether an attribute with key was set on this edge
+    pub(crate) fn has_attribute(&self, key: &str) -> bool {
+        self.attributes.iter().any(|(k, _)| k == key)
+    }
+
+    /// Create the DOT attribute list for this edge, including the leading space, or an empty
+    /// string if there are no attributes
+    ///
+    /// The default attributes of the kind of this edge come first, then the attributes the
+    /// current [Graph] sets on edges it selects, then the attributes set on this edge, and
+    /// later attributes override the value of earlier ones with the same key.
+    ///
+    /// [Graph]: crate::Graph
+    pub(crate) fn render_attributes(&self) -> String {
+        let options = options::current();
+        let default_attributes = self
+            .kind
+            .map(EdgeKind::default_attributes)
+            .unwrap_or_default()
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()));
+        let graph_attributes = options
+            .edge_attributes
+            .iter()
+            .filter(|(selector, _, _)| selector.matches(self.kind))
+            .map(|(_, key, value)| (key.clone(), value.clone()));
+        let mut attributes = Vec::new();
+        for (key, value) in default_attributes
+            .chain(graph_attributes)
+            .chain(self.attributes.iter().cloned())
+        {
+            attributes::set(&mut attributes, key, value);
+        }
+        match attributes.len() {
+            0 => String::new(),
+            _ => format!(
+                " {}",
+                util::render_attribute_list(
+                    attributes
+                        .iter()
+                        .map(|(key, value)| (key.as_str(), value.as_str()))
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render_attributes() {
+        assert_eq!(Edge::default().render_attributes(), "");
+        assert_eq!(
+            Edge::default()
+                .with_attribute("style", "dashed")
+                .with_attribute("label", "say \"hi\"")
+                .render_attributes(),
+            " [style=\"dashed\", label=\"say \\\"hi\\\"\"]"
+        );
+    }
+
+    #[test]
+    fn test_render_kind_attributes() {
+        assert_eq!(
+            Edge::default()
+                .with_kind(EdgeKind::SharedBorrow)
+                .render_attributes(),
+            ""
+        );
+        assert_eq!(
+            Edge::default()
+                .with_kind(EdgeKind::Weak)
+                .with_attribute("constraint", "true")
+                .render_attributes(),
+            " [style=\"dashed\", constraint=\"true\"]"
+        );
+    }
+
+    #[test]
+    fn test_render_selected_attributes() {
+        let options = options::Options {
+            edge_attributes: vec![
+                (EdgeSelector::All, "penwidth".into(), "2".into()),
+                (EdgeSelector::Unkinded, "color".into(), "red".into()),
+                (EdgeKind::Raw.into(), "color".into(), "blue".into()),
+            ],
+            ..Default::default()
+        };
+        options::with(&options, || {
+            assert_eq!(
+                Edge::default().render_attributes(),
+                " [penwidth=\"2\", color=\"red\"]"
+            );
+            assert_eq!(
+                Edge::default().with_kind(EdgeKind::Raw).render_attributes(),
+                " [style=\"dotted\", penwidth=\"2\", color=\"blue\"]"
+            );
+            assert_eq!(
+                Edge::default()
+                    .with_kind(EdgeKind::SharedBorrow)
+                    .render_attributes(),
+                " [penwidth=\"2\"]"
+            );
+        });
+    }
+}
@@ -1,5 +1,10 @@
 use std::io::{Result, Write};
+use std::time::Instant;
 
+use crate::attributes::{self, EdgeOrdering, RankDir, Splines};
+use crate::edge::{EdgeKind, EdgeSelector};
+use crate::options::{self, Options};
+use crate::theme::Theme;
 use crate::Visualize;
 
 #[derive(Debug, Clone)]
@@ -48,19 +53,50 @@ use crate::Visualize;
 ///     .write_to(&mut dot_file)
 ///     .unwrap();
 /// ```
+///
+/// # Options
+///
+/// Each node is rendered when it is added, with the options set on the graph at that time, so
+/// options like [Graph::set_memory_layout], [Graph::set_edge_attribute] and the cell styles of
+/// [Graph::set_theme] only affect nodes added after they are set. Set them before adding nodes:
+/// `Graph::new().add_node(&x).set_theme(theme)` changes the background and fonts of the graph,
+/// but not the cells of x.
+///
+/// The attributes of the graph, its default node and edge attributes and the legend are rendered
+/// by [Graph::render], so they apply no matter when they are set.
 pub struct Graph {
     /// The ID of the graph in the DOT language grammar
     id: String,
     /// The string containing the dot file contents, to eventually be written to a file
     buffer: String,
+    /// The options used when rendering nodes added to this graph
+    options: Options,
+    /// Whether to render a legend explaining the style of each [EdgeKind]
+    legend: bool,
+    /// DOT attributes of the graph itself
+    graph_attributes: Vec<(String, String)>,
+    /// Default DOT attributes of every node
+    node_attributes: Vec<(String, String)>,
+    /// Default DOT attributes of every edge
+    edge_attributes: Vec<(String, String)>,
 }
 
 impl Graph {
     /// Create a new graph
+    ///
+    /// Every [Instant] in the graph is rendered relative to the moment it was created.
     pub fn new() -> Graph {
         Graph {
             id: String::from("visualization"),
             buffer: String::new(),
+            options: Options {
+                reference_instant: Some(Instant::now()),
+                ..Options::default()
+            },
+            legend: false,
+            graph_attributes: Vec::new(),
+            node_attributes: vec![("shape".into(), "plaintext".into())],
+            edge_attributes: Vec::new(),
         }
     }
 
@@ -74,24 +110,286 @@ impl Graph {
         }
     }
 
+    /// Set a DOT attribute of the graph itself, replacing its previous value
+    ///
+    /// See the DOT attribute docs for the available attributes:
+    /// <https://graphviz.org/doc/info/attrs.html>
+    pub fn set_graph_attribute<K, V>(mut self, key: K, value: V) -> Graph
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        attributes::set(&mut self.graph_attributes, key.into(), value.into());
+        self
+    }
+
+    /// Set a default DOT attribute of every node, replacing its previous value
+    ///
+    /// Nodes are tables, so the default `shape` is `plaintext`, and changing it draws a shape
+    /// around each table.
+    pub fn set_default_node_attribute<K, V>(mut self, key: K, value: V) -> Graph
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        attributes::set(&mut self.node_attributes, key.into(), value.into());
+        self
+    }
+
+    /// Set a default DOT attribute of every edge, replacing its previous value
+    ///
+    /// The style of each [EdgeKind] and the attributes set by [Graph::set_edge_attribute] and
+    /// [Visualize] implementations override these.
+    pub fn set_default_edge_attribute<K, V>(mut self, key: K, value: V) -> Graph
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        attributes::set(&mut self.edge_attributes, key.into(), value.into());
+        self
+    }
+
+    /// Set the direction the graph is laid out in
+    pub fn set_rankdir(self, rankdir: RankDir) -> Graph {
+        self.set_graph_attribute("rankdir", rankdir.as_str())
+    }
+
+    /// Set how edges are drawn
+    pub fn set_splines(self, splines: Splines) -> Graph {
+        self.set_graph_attribute("splines", splines.as_str())
+    }
+
+    /// Keep the edges of each node in the order they were added in, which is needed to keep the
+    /// left and right children of a binary tree in order
+    pub fn set_ordering(self, ordering: EdgeOrdering) -> Graph {
+        self.set_graph_attribute("ordering", ordering.as_str())
+    }
+
+    /// Set the minimum space between nodes in the same rank, in inches
+    pub fn set_nodesep(self, inches: f64) -> Graph {
+        self.set_graph_attribute("nodesep", inches.to_string())
+    }
+
+    /// Set the minimum space between ranks, in inches
+    pub fn set_ranksep(self, inches: f64) -> Graph {
+        self.set_graph_attribute("ranksep", inches.to_string())
+    }
+
+    /// Set a label shown on the graph, like a title
+    pub fn set_label(self, label: impl Into<String>) -> Graph {
+        self.set_graph_attribute("label", label)
+    }
+
+    /// Set the font of the graph label, the nodes and the edge labels
+    pub fn set_fontname(self, fontname: impl Into<String>) -> Graph {
+        let fontname = fontname.into();
+        self.set_graph_attribute("fontname", fontname.clone())
+            .set_default_node_attribute("fontname", fontname.clone())
+            .set_default_edge_attribute("fontname", fontname)
+    }
+
+    /// Set the font size of the graph label, the nodes and the edge labels, in points
+    pub fn set_fontsize(self, points: f64) -> Graph {
+        let fontsize = points.to_string();
+        self.set_graph_attribute("fontsize", fontsize.clone())
+            .set_default_node_attribute("fontsize", fontsize.clone())
+            .set_default_edge_attribute("fontsize", fontsize)
+    }
+
+    /// Set the colors and fonts of the graph
+    ///
+    /// The background, fonts and edge color are set as graph, default node and default edge
+    /// attributes, which can be overridden afterwards. The table cell styles are an option, see
+    /// [Graph#options].
+    pub fn set_theme(mut self, theme: Theme) -> Graph {
+        for (key, value) in theme.graph_attributes() {
+            attributes::set(&mut self.graph_attributes, key.into(), value.into());
+        }
+        for (key, value) in theme.node_attributes() {
+            attributes::set(&mut self.node_attributes, key.into(), value.into());
+        }
+        for (key, value) in theme.edge_attributes() {
+            attributes::set(&mut self.edge_attributes, key.into(), value.into());
+        }
+        self.options.theme = theme;
+        self
+    }
+
+    /// Render each [BinaryHeap] as the binary tree its backing array represents, with an edge from
+    /// the element at index i to the elements at 2i+1 and 2i+2, rather than as a list
+    ///
+    /// [BinaryHeap]: std::collections::BinaryHeap
+    pub fn set_binary_heaps_as_trees(mut self, enabled: bool) -> Graph {
+        self.options.binary_heaps_as_trees = enabled;
+        self
+    }
+
+    /// Render each [VecDeque] as its ring buffer, showing its capacity and its front and back
+    /// segments in memory order, with the unused slots between them when the deque wraps around,
+    /// rather than as a list in logical order
+    ///
+    /// [VecDeque]: std::collections::VecDeque
+    pub fn set_vec_deques_as_ring_buffers(mut self, enabled: bool) -> Graph {
+        self.options.vec_deques_as_ring_buffers = enabled;
+        self
+    }
+
+    /// Render each [Vec] and [String] as its (ptr, len, capacity) header with an edge to the heap
+    /// buffer holding its contents, where the spare capacity is shown greyed out, rather than as
+    /// its contents alone
+    pub fn set_heap_buffers(mut self, enabled: bool) -> Graph {
+        self.options.heap_buffers = enabled;
+        self
+    }
+
+    /// Render the size and alignment of all data, and render the fields of structs, or anything
+    /// else that lies inside of the data that owns it, in the order they sit in memory, with their
+    /// offsets and with rows for the padding between them
+    pub fn set_memory_layout(mut self, enabled: bool) -> Graph {
+        self.options.memory_layout = enabled;
+        self
+    }
+
+    /// Render the discriminant of each enum deriving [Visualize], its size next to the size of its
+    /// largest variant, whether its tag is stored in a niche of its fields rather than taking extra
+    /// space, and the bytes of its tag when they are known, which is when its `#[repr]` stores the
+    /// tag at its start or it has no fields
+    pub fn set_enum_layouts(mut self, enabled: bool) -> Graph {
+        self.options.enum_layouts = enabled;
+        self
+    }
+
+    /// Render a hex and ASCII dump of the bytes of the data at the root of each graph node
+    ///
+    /// Bytes which might be uninitialized padding are not read, and are marked as unknown. To dump
+    /// a single field instead, use the `#[vizz(hex)]` field attribute of the derive macro, or
+    /// [DataDescription::with_hex_dump].
+    ///
+    /// [DataDescription::with_hex_dump]: crate::DataDescription::with_hex_dump
+    pub fn set_hex_dumps(mut self, enabled: bool) -> Graph {
+        self.options.hex_dumps = enabled;
+        self
+    }
+
+    /// Render the sign, exponent and mantissa bits of each [f32] and [f64], in addition to the
+    /// shortest decimal which converts back to exactly the same float
+    pub fn set_float_bits(mut self, enabled: bool) -> Graph {
+        self.options.float_bits = enabled;
+        self
+    }
+
+    /// Render the NUL byte terminating each [CStr] and [CString], as `\0`
+    ///
+    /// [CStr]: std::ffi::CStr
+    /// [CString]: std::ffi::CString
+    pub fn set_c_string_nuls(mut self, enabled: bool) -> Graph {
+        self.options.c_string_nuls = enabled;
+        self
+    }
+
+    /// Render the data inside of each [MaybeUninit] as initialized, rather than as opaque bytes
+    ///
+    /// # Safety
+    ///
+    /// While this is enabled, every [MaybeUninit] reachable from the nodes added must be fully
+    /// initialized.
+    ///
+    /// [MaybeUninit]: std::mem::MaybeUninit
+    pub unsafe fn set_maybe_uninits_assumed_initialized(mut self, enabled: bool) -> Graph {
+        self.options.maybe_uninits_assumed_initialized = enabled;
+        self
+    }
+
+    /// Render a legend explaining the style of the edge drawn for each [EdgeKind] of pointer
+    pub fn set_legend(self, enabled: bool) -> Graph {
+        Graph {
+            legend: enabled,
+            ..self
+        }
+    }
+
+    /// Label each edge with the name of the field it is drawn from, or for data without a name,
+    /// like the fields of a tuple or the elements of a collection, its index
+    ///
+    /// Edges which already have a label, like the reference counts of an [Rc], keep it.
+    ///
+    /// [Rc]: std::rc::Rc
+    pub fn set_edge_labels(mut self, enabled: bool) -> Graph {
+        self.options.edge_labels = enabled;
+        self
+    }
+
+    /// Set a DOT attribute, such as `color`, `penwidth` or `constraint`, on every edge of a kind,
+    /// or on the edges another [EdgeSelector] selects, like [EdgeSelector::All]
+    ///
+    /// This overrides the default style of the kind, but not attributes set by a [Visualize]
+    /// implementation with [Value::with_edge_attribute]. See the DOT attribute docs for the
+    /// available attributes: <https://graphviz.org/doc/info/attrs.html>
+    ///
+    /// [Value::with_edge_attribute]: crate::Value::with_edge_attribute
+    pub fn set_edge_attribute<S, K, V>(mut self, selector: S, key: K, value: V) -> Graph
+    where
+        S: Into<EdgeSelector>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.options
+            .edge_attributes
+            .push((selector.into(), key.into(), value.into()));
+        self
+    }
+
     /// Add a data structure that implements [Visualize] to the [Graph]
     pub fn add_node<V>(self, node: &V) -> Graph
     where
-        V: Visualize,
+        V: ?Sized + Visualize,
     {
+        let rendered_node = options::with(&self.options, || node.render_node());
         Graph {
-            buffer: self.buffer + node.render_node().inner(),
+            buffer: self.buffer + rendered_node.inner(),
             ..self
         }
     }
 
+    /// Add a data structure that implements [Visualize] to the [Graph], rendering the data its raw
+    /// pointers point to
+    ///
+    /// [Graph::add_node] only renders the address and type a raw pointer points to, because
+    /// following it might not be safe.
+    ///
+    /// # Safety
+    ///
+    /// Every non-null raw pointer or [NonNull] reachable from node must point to a valid,
+    /// initialized value of its pointee type, which must not be written to until this returns.
+    ///
+    /// [NonNull]: std::ptr::NonNull
+    pub unsafe fn add_node_following_raw_pointers<V>(mut self, node: &V) -> Graph
+    where
+        V: ?Sized + Visualize,
+    {
+        let follow_raw_pointers = self.options.follow_raw_pointers;
+        self.options.follow_raw_pointers = true;
+        let mut graph = self.add_node(node);
+        graph.options.follow_raw_pointers = follow_raw_pointers;
+        graph
+    }
+
     /// Create the full DOT graph file contents as a [String]
     pub fn render(&self) -> String {
+        let legend = match self.legend {
+            true => options::with(&self.options, EdgeKind::render_legend),
+            false => String::new(),
+        };
         format!(
             r#"digraph {} {{
-{}
+{}{}{}{}{}
 }}"#,
-            self.id, self.buffer
+            self.id,
+            attributes::render_statement("graph", &self.graph_attributes),
+            attributes::render_statement("node", &self.node_attributes),
+            attributes::render_statement("edge", &self.edge_attributes),
+            self.buffer,
+            legend
         )
     }
 
@@ -103,7 +401,7 @@ impl Graph {
 
 impl<V> From<&V> for Graph
 where
-    V: Visualize,
+    V: ?Sized + Visualize,
 {
     fn from(v: &V) -> Self {
         Graph::new().add_node(v)
@@ -118,6 +416,8 @@ impl Default for Graph {
 
 #[cfg(test)]
 mod test {
+    use std::ops::Deref;
+
     use crate::Address;
 
     use super::*;
@@ -130,6 +430,77 @@ mod test {
         let target_ref_address = Address::new(&target_ref);
         let graph_id = "test_generate_graph";
         let graph = Graph::new().set_id(graph_id).add_node(&target_ref);
-        assert_eq!(graph.render(), format!("digraph {0} {{\n  node [shape=plaintext]\n    \"{1}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{1}-address\"><I>{1}</I></TD><TD PORT=\"{1}-type\"><B>&amp;alloc::string::String</B></TD><TD PORT=\"{1}-value\"></TD></TR></TABLE>>];\n    \"{1}\":\"{1}-value\" -> \"{2}\":\"{2}-address\"\n  node [shape=plaintext]\n    \"{2}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{2}-address\"><I>{2}</I></TD><TD PORT=\"{2}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{2}-value\">test</TD></TR></TABLE>>];\n    \n\n}}", graph_id, target_ref_address, target_address));
+        assert_eq!(graph.render(), format!("digraph {0} {{\n  node [shape=\"plaintext\"]\n    \"{1}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{1}-address\"><I>{1}</I></TD><TD PORT=\"{1}-type\"><B>&amp;alloc::string::String</B></TD><TD PORT=\"{1}-value\"></TD></TR></TABLE>>];\n    \"{1}\":\"{1}-value\" -> \"{2}\":\"{2}-address\"\n    \"{2}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{2}-address\"><I>{2}</I></TD><TD PORT=\"{2}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{2}-value\">test</TD></TR></TABLE>>];\n    \n\n}}", graph_id, target_ref_address, target_address));
+    }
+
+    #[test]
+    fn test_render_legend() {
+        assert!(!Graph::new().render().contains("cluster_legend"));
+
+        let rendered = Graph::new().set_legend(true).render();
+        assert!(rendered.contains("  subgraph cluster_legend {\n    label=\"legend\"\n"));
+        assert!(rendered.contains("    \"legend-2\" [label=<Box&lt;T&gt; owning>];\n    \"legend-2-target\" [label=\"\", shape=point];\n    \"legend-2\" -> \"legend-2-target\" [dir=\"both\", arrowtail=\"diamond\"]\n"));
+    }
+
+    #[test]
+    fn test_render_edge_labels_and_attributes() {
+        let first = 1u8;
+        let second = Box::new(2u8);
+        let pair = (&first, second);
+        let first_address = Address::new(&first);
+        let second_address = Address::new(pair.1.deref());
+
+        let unlabeled = Graph::new().add_node(&pair).render();
+        assert!(!unlabeled.contains("label=\""));
+
+        let rendered = Graph::new()
+            .set_edge_labels(true)
+            .set_edge_attribute(EdgeKind::Owning, "color", "red")
+            .set_edge_attribute(EdgeKind::Owning, "arrowtail", "none")
+            .set_edge_attribute(EdgeSelector::All, "penwidth", "2")
+            .add_node(&pair)
+            .render();
+        assert!(rendered.contains(&format!(
+            "-> \"{0}\":\"{0}-address\" [penwidth=\"2\", label=\"0\"]\n",
+            first_address
+        )));
+        assert!(rendered.contains(&format!(
+            "-> \"{0}\":\"{0}-address\" [dir=\"both\", arrowtail=\"none\", color=\"red\", penwidth=\"2\", label=\"1\"]\n",
+            second_address
+        )));
+    }
+
+    #[test]
+    fn test_render_header_attributes() {
+        let graph = Graph::new()
+            .set_id("tree")
+            .set_rankdir(RankDir::LeftToRight)
+            .set_splines(Splines::Ortho)
+            .set_ordering(EdgeOrdering::Out)
+            .set_nodesep(0.5)
+            .set_label("my tree")
+            .set_fontname("Helvetica")
+            .set_default_node_attribute("shape", "box")
+            .set_default_edge_attribute("penwidth", "2")
+            .set_rankdir(RankDir::BottomToTop);
+        assert_eq!(
+            graph.render(),
+            "digraph tree {\n  graph [rankdir=\"BT\", splines=\"ortho\", ordering=\"out\", nodesep=\"0.5\", label=\"my tree\", fontname=\"Helvetica\"]\n  node [shape=\"box\", fontname=\"Helvetica\"]\n  edge [fontname=\"Helvetica\", penwidth=\"2\"]\n\n}"
+        );
+    }
+
+    #[test]
+    fn test_render_theme() {
+        let target = String::from("green");
+        let target_address = Address::new(&target);
+        let rendered = Graph::new()
+            .set_theme(Theme::print().with_type_color("String", "palegreen"))
+            .add_node(&target)
+            .render();
+        assert!(rendered.starts_with("digraph visualization {\n  graph [bgcolor=\"white\", fontcolor=\"black\"]\n  node [shape=\"plaintext\", fontcolor=\"black\"]\n  edge [color=\"black\", fontcolor=\"black\"]\n"));
+        assert!(rendered.contains(&format!(
+            "<TD PORT=\"{0}-value\" BGCOLOR=\"palegreen\" COLOR=\"black\">green</TD>",
+            target_address
+        )));
     }
 }
@@ -1,9 +1,34 @@
 use crate::address::Address;
 use crate::data_description::DataDescription;
 use crate::data_description::Value;
+use crate::edge::EdgeKind;
+use crate::node::RenderedNode;
+use crate::options;
+use crate::util;
 use crate::Visualize;
+use crate::VisualizeDyn;
 
-use std::ops::Deref;
+use std::borrow::{Cow, ToOwned};
+use std::cell::{Cell, LazyCell, OnceCell, RefCell};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+use std::ops::{
+    Bound, Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use std::path::{Path, PathBuf};
+use std::ptr::NonNull;
+use std::rc::{self, Rc};
+use std::sync::atomic::{self, AtomicBool, AtomicPtr};
+use std::sync::{self, Arc, LazyLock, Mutex, OnceLock, RwLock, TryLockError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 macro_rules! impl_visualize_data_to_string {
     ($ty:ident) => {
@@ -13,18 +38,144 @@ macro_rules! impl_visualize_data_to_string {
             }
         }
     };
-    (&$ty:ident) => {
-        impl Visualize for &$ty {
+}
+
+impl_visualize_data_to_string!(bool);
+impl_visualize_data_to_string!(char);
+impl_visualize_data_to_string!(str);
+impl_visualize_data_to_string!(IpAddr);
+impl_visualize_data_to_string!(Ipv4Addr);
+impl_visualize_data_to_string!(Ipv6Addr);
+impl_visualize_data_to_string!(SocketAddr);
+impl_visualize_data_to_string!(SocketAddrV4);
+impl_visualize_data_to_string!(SocketAddrV6);
+
+// Debug is the human readable one, like 1.5s
+impl Visualize for Duration {
+    fn data(&self) -> Option<Value> {
+        Some(Value::Owned(format!("{:?}", self)))
+    }
+}
+
+/// Render a time as an RFC 3339 UTC timestamp, from the whole seconds since [UNIX_EPOCH], which
+/// are negative before it, and the nanoseconds after those seconds
+fn render_rfc3339(seconds: i64, nanos: u32) -> String {
+    let days = seconds.div_euclid(86400);
+    let second_of_day = seconds.rem_euclid(86400);
+
+    // convert days since the epoch to a proleptic Gregorian date, in 400 year eras starting on
+    // March 1st so leap days fall at the end of the year, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let shifted_days = days + 719468;
+    let era = shifted_days.div_euclid(146097);
+    let day_of_era = shifted_days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let fraction = match nanos {
+        0 => String::new(),
+        nanos => format!(".{:09}", nanos).trim_end_matches('0').to_string(),
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        year,
+        month,
+        day,
+        second_of_day / 3600,
+        second_of_day / 60 % 60,
+        second_of_day % 60,
+        fraction
+    )
+}
+
+impl Visualize for SystemTime {
+    fn data(&self) -> Option<Value> {
+        let (seconds, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(since) => (since.as_secs() as i64, since.subsec_nanos()),
+            Err(error) => {
+                let until = error.duration();
+                match until.subsec_nanos() {
+                    0 => (-(until.as_secs() as i64), 0),
+                    nanos => (-(until.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+        Some(Value::Owned(render_rfc3339(seconds, nanos)))
+    }
+}
+
+// an Instant is opaque, so it can only be shown relative to another one
+impl Visualize for Instant {
+    fn data(&self) -> Option<Value> {
+        let (reference, name) = match options::current().reference_instant {
+            Some(reference) => (reference, "graph created"),
+            None => (Instant::now(), "now"),
+        };
+        Some(Value::Owned(match self.checked_duration_since(reference) {
+            Some(after) => format!("{} + {:?}", name, after),
+            None => format!("{} - {:?}", name, reference.duration_since(*self)),
+        }))
+    }
+}
+
+/// Decode bytes as UTF-8, escaping each byte which isn't part of a valid character as `\xNN`
+fn escape_invalid_utf8(bytes: &[u8]) -> String {
+    bytes
+        .utf8_chunks()
+        .map(|chunk| {
+            chunk
+                .invalid()
+                .iter()
+                .fold(chunk.valid().to_string(), |acc, byte| {
+                    acc + &format!("\\x{:02x}", byte)
+                })
+        })
+        .collect()
+}
+
+macro_rules! impl_visualize_os_str {
+    ($($ty:ident)+) => {
+        $(
+        // the encoded bytes are UTF-8 wherever the string is valid Unicode, on every platform
+        impl Visualize for $ty {
             fn data(&self) -> Option<Value> {
-                Some(Value::Owned(self.to_string()))
+                let os_str: &OsStr = self.as_ref();
+                Some(Value::Owned(escape_invalid_utf8(os_str.as_encoded_bytes())))
             }
         }
+        )+
     };
 }
 
-impl_visualize_data_to_string!(bool);
-impl_visualize_data_to_string!(char);
-impl_visualize_data_to_string!(&str);
+impl_visualize_os_str!(OsStr OsString Path PathBuf);
+
+macro_rules! impl_visualize_c_str {
+    ($($ty:ident)+) => {
+        $(
+        impl Visualize for $ty {
+            fn data(&self) -> Option<Value> {
+                let c_str: &CStr = self.as_ref();
+                let mut string = escape_invalid_utf8(c_str.to_bytes());
+                if options::current().c_string_nuls {
+                    string.push_str("\\0");
+                }
+                Some(Value::Owned(string))
+            }
+        }
+        )+
+    };
+}
+
+impl_visualize_c_str!(CStr CString);
 
 macro_rules! impl_visualize_num {
     ($ty:ident) => {
@@ -44,6 +195,156 @@ impl_visualize_num!(i32);
 impl_visualize_num!(i64);
 impl_visualize_num!(i128);
 impl_visualize_num!(isize);
+impl_visualize_num!(NonZeroU8);
+impl_visualize_num!(NonZeroU16);
+impl_visualize_num!(NonZeroU32);
+impl_visualize_num!(NonZeroU64);
+impl_visualize_num!(NonZeroU128);
+impl_visualize_num!(NonZeroUsize);
+impl_visualize_num!(NonZeroI8);
+impl_visualize_num!(NonZeroI16);
+impl_visualize_num!(NonZeroI32);
+impl_visualize_num!(NonZeroI64);
+impl_visualize_num!(NonZeroI128);
+impl_visualize_num!(NonZeroIsize);
+
+macro_rules! impl_visualize_float {
+    ($ty:ident, $exponent_bits:expr, $mantissa_bits:expr) => {
+        impl Visualize for $ty {
+            // Display is the shortest decimal which converts back to exactly this float
+            fn data(&self) -> Option<Value> {
+                Some(Value::Owned(self.to_string()))
+            }
+
+            fn associated_data(&self) -> Option<Vec<DataDescription>> {
+                if !options::current().float_bits {
+                    return None;
+                }
+
+                let bits = self.to_bits();
+                let exponent_mask = (1 << $exponent_bits) - 1;
+                let exponent = (bits >> $mantissa_bits) & exponent_mask;
+                let mantissa = bits & ((1 << $mantissa_bits) - 1);
+                Some(float_bit_rows(
+                    &Address::new(self),
+                    self.is_sign_negative(),
+                    format!("{:0width$b}", exponent, width = $exponent_bits),
+                    match exponent {
+                        0 if mantissa == 0 => "zero".into(),
+                        0 => format!("subnormal, 2^{}", 2 - (1 << ($exponent_bits - 1))),
+                        exponent if exponent == exponent_mask && mantissa == 0 => "infinite".into(),
+                        exponent if exponent == exponent_mask => "NaN".into(),
+                        exponent => format!("2^{}", exponent as i32 - exponent_mask as i32 / 2),
+                    },
+                    format!(
+                        "{:0width$x}",
+                        mantissa,
+                        width = usize::div_ceil($mantissa_bits, 4)
+                    ),
+                ))
+            }
+        }
+    };
+}
+
+impl_visualize_float!(f32, 8, 23);
+impl_visualize_float!(f64, 11, 52);
+
+/// Describe the sign, exponent and mantissa bits of a float at address
+fn float_bit_rows(
+    address: &Address,
+    negative: bool,
+    exponent_bits: String,
+    exponent: String,
+    mantissa_bits: String,
+) -> Vec<DataDescription> {
+    vec![
+        DataDescription::computed(
+            address,
+            "sign",
+            Value::Owned(match negative {
+                true => "1 (-)".into(),
+                false => "0 (+)".into(),
+            }),
+        ),
+        DataDescription::computed(
+            address,
+            "exponent",
+            Value::Owned(format!("{} ({})", exponent_bits, exponent)),
+        ),
+        DataDescription::computed(
+            address,
+            "mantissa",
+            Value::Owned(format!("0x{}", mantissa_bits)),
+        ),
+    ]
+}
+
+macro_rules! impl_visualize_transparent_num {
+    ($ty:ident) => {
+        // the wrapper only changes the arithmetic, so it renders as the number it wraps
+        impl<T> Visualize for $ty<T>
+        where
+            T: Visualize,
+        {
+            fn data(&self) -> Option<Value> {
+                self.0.data()
+            }
+
+            fn associated_data(&self) -> Option<Vec<DataDescription>> {
+                self.0.associated_data()
+            }
+        }
+    };
+}
+
+impl_visualize_transparent_num!(Wrapping);
+impl_visualize_transparent_num!(Saturating);
+
+/// The memory ordering atomics are loaded with
+///
+/// A sequentially consistent load sees the latest value in the single total order of every
+/// sequentially consistent operation, which is the closest thing to "the current value" there is.
+/// Other threads may still change the value right after it is captured.
+const ATOMIC_LOAD_ORDERING: atomic::Ordering = atomic::Ordering::SeqCst;
+
+macro_rules! impl_visualize_atomic {
+    ($($size:literal => $($ty:ident)+),+) => {
+        $($(
+        #[cfg(target_has_atomic = $size)]
+        impl Visualize for atomic::$ty {
+            fn data(&self) -> Option<Value> {
+                Some(Value::Owned(self.load(ATOMIC_LOAD_ORDERING).to_string()))
+            }
+        }
+        )+)+
+    };
+}
+
+impl_visualize_atomic!(
+    "8" => AtomicI8 AtomicU8,
+    "16" => AtomicI16 AtomicU16,
+    "32" => AtomicI32 AtomicU32,
+    "64" => AtomicI64 AtomicU64,
+    "ptr" => AtomicIsize AtomicUsize
+);
+
+#[cfg(target_has_atomic = "8")]
+impl Visualize for AtomicBool {
+    fn data(&self) -> Option<Value> {
+        Some(Value::Owned(self.load(ATOMIC_LOAD_ORDERING).to_string()))
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Visualize for AtomicPtr<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        Some(raw_pointer_value(self.load(ATOMIC_LOAD_ORDERING)))
+    }
+}
 
 impl<V, const N: usize> Visualize for [V; N]
 where
@@ -57,13 +358,15 @@ where
     }
 }
 
-impl<'a, V> Visualize for &'a [V]
+impl<V> Visualize for [V]
 where
     V: Visualize,
 {
-    fn data(&self) -> Option<Value> {
-        (!self.is_empty())
-            .then(|| Value::referenced(Address::new(&self[0]), DataDescription::from(&self[0])))
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        match self.len() {
+            0 => None,
+            _ => Some(self.iter().map(DataDescription::from).collect()),
+        }
     }
 }
 
@@ -100,21 +403,237 @@ tuple_impls! {
     16 => (0 T0 1 T1 2 T2 3 T3 4 T4 5 T5 6 T6 7 T7 8 T8 9 T9 10 T10 11 T11 12 T12 13 T13 14 T14 15 T15)
 }
 
+/// The most unused slots of a heap buffer rendered individually, past which the rest are summarized
+/// in a single row
+const MAX_UNUSED_SLOTS: usize = 8;
+
+/// Describe the spare capacity of a heap buffer, starting at slot len
+fn describe_unused_slots(
+    buffer_address: &Address,
+    len: usize,
+    capacity: usize,
+) -> impl Iterator<Item = DataDescription> + '_ {
+    let listed = capacity.min(len + MAX_UNUSED_SLOTS);
+    let listed_slots = (len..listed).map(move |slot| {
+        DataDescription::unused(
+            buffer_address,
+            format!("[{}]", slot),
+            Value::Owned(String::new()),
+        )
+    });
+    let remaining_slots = (listed < capacity).then(|| {
+        DataDescription::unused(
+            buffer_address,
+            format!("[{}..{}]", listed, capacity),
+            Value::Owned(format!("{} more", capacity - listed)),
+        )
+    });
+    listed_slots.chain(remaining_slots)
+}
+
+/// Describe the (ptr, len, capacity) header of a heap buffer, where ptr has an edge to the buffer
+///
+/// A buffer of [None] has no allocation, so its ptr is rendered as dangling.
+fn describe_heap_buffer_header(
+    owner_address: &Address,
+    buffer: Option<DataDescription>,
+    len: usize,
+    capacity: usize,
+) -> Vec<DataDescription> {
+    let ptr = match buffer {
+        Some(buffer) => {
+            Value::referenced(buffer.address.clone(), buffer).with_edge_kind(EdgeKind::Owning)
+        }
+        None => Value::Owned("dangling".into()),
+    };
+    vec![
+        DataDescription::computed(owner_address, "ptr", ptr),
+        DataDescription::computed(owner_address, "len", Value::Owned(len.to_string())),
+        DataDescription::computed(
+            owner_address,
+            "capacity",
+            Value::Owned(capacity.to_string()),
+        ),
+    ]
+}
+
 impl Visualize for String {
     fn data(&self) -> Option<Value> {
-        Some(Value::Owned(self.clone()))
+        (!options::current().heap_buffers).then(|| Value::Owned(self.clone()))
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        if !options::current().heap_buffers {
+            return None;
+        }
+
+        // each char's bytes share a row, so the rows mark the char boundaries
+        let buffer = (self.capacity() > 0).then(|| {
+            let buffer_address = Address::from_ptr(self.as_ptr());
+            let chars = self.char_indices().map(|(index, c)| {
+                let bytes = &self.as_bytes()[index..index + c.len_utf8()];
+                DataDescription::computed(
+                    &buffer_address,
+                    format!("[{}..{}]", index, index + bytes.len()),
+                    Value::Owned(format!(
+                        "{} {:?}",
+                        bytes
+                            .iter()
+                            .map(|byte| format!("{:02x}", byte))
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                        c
+                    )),
+                )
+            });
+            chars
+                .chain(describe_unused_slots(
+                    &buffer_address,
+                    self.len(),
+                    self.capacity(),
+                ))
+                .fold(
+                    DataDescription::new(
+                        buffer_address.clone(),
+                        format!("[u8; {}]", self.capacity()),
+                    ),
+                    DataDescription::with_associated_data,
+                )
+        });
+
+        Some(describe_heap_buffer_header(
+            &Address::new(self),
+            buffer,
+            self.len(),
+            self.capacity(),
+        ))
     }
 }
 
 impl<V> Visualize for &V
 where
-    V: Visualize,
+    V: ?Sized + VisualizeDyn,
 {
     fn data(&self) -> Option<Value> {
-        Some(Value::referenced(
-            Address::new(*self),
-            DataDescription::from(*self),
-        ))
+        Some(VisualizeDyn::reference(*self).with_edge_kind(EdgeKind::SharedBorrow))
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        trait_object_rows(&Address::new(self), *self)
+    }
+}
+
+impl<V> Visualize for &mut V
+where
+    V: ?Sized + VisualizeDyn,
+{
+    fn data(&self) -> Option<Value> {
+        Some(VisualizeDyn::reference(&**self).with_edge_kind(EdgeKind::UniqueBorrow))
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        trait_object_rows(&Address::new(self), &**self)
+    }
+}
+
+/// Describe the concrete type of the trait object t, and the data and vtable addresses of a fat
+/// pointer to it, which is owned by the data at owner_address
+///
+/// This is [None] when t isn't a trait object. The order of the words of a fat pointer isn't
+/// guaranteed, so the vtable address is only shown when one of them is the data address.
+fn trait_object_rows<T>(owner_address: &Address, t: &T) -> Option<Vec<DataDescription>>
+where
+    T: ?Sized + VisualizeDyn,
+{
+    if !std::any::type_name::<T>().starts_with("dyn ") {
+        return None;
+    }
+
+    let pointer: *const T = t;
+    let data = pointer as *const u8 as usize;
+    let mut rows = vec![
+        DataDescription::computed(
+            owner_address,
+            "concrete type",
+            Value::Owned(t.type_name().into()),
+        ),
+        DataDescription::computed(owner_address, "data", Value::Owned(format!("{:#x}", data))),
+    ];
+
+    if size_of::<*const T>() == size_of::<[usize; 2]>() {
+        // SAFETY: the pointer is exactly two words long, and any bits are a valid usize
+        let words: [usize; 2] = unsafe { std::mem::transmute_copy(&pointer) };
+        let vtable = match words {
+            [first, vtable] if first == data => Some(vtable),
+            [vtable, second] if second == data => Some(vtable),
+            _ => None,
+        };
+        if let Some(vtable) = vtable {
+            rows.push(DataDescription::computed(
+                owner_address,
+                "vtable",
+                Value::Owned(format!("{:#x}", vtable)),
+            ));
+        }
+    }
+
+    Some(rows)
+}
+
+/// The value of a null pointer, which is the electrical ground symbol
+const NULL: &str = "⏚";
+
+/// Create the [Value] of a raw pointer
+///
+/// The data it points to is only described when the caller of
+/// [Graph::add_node_following_raw_pointers] has vouched that this is safe, and otherwise only
+/// its address and type are rendered.
+///
+/// [Graph::add_node_following_raw_pointers]: crate::Graph::add_node_following_raw_pointers
+fn raw_pointer_value<T>(ptr: *const T) -> Value
+where
+    T: Visualize,
+{
+    if ptr.is_null() {
+        return Value::Owned(NULL.into());
+    }
+
+    let address = Address::from_ptr(ptr);
+    let value = if options::current().follow_raw_pointers {
+        // SAFETY: the caller of Graph::add_node_following_raw_pointers vouches that every non-null
+        // raw pointer points to a valid value which won't be written to in the meantime
+        Value::reference_to(unsafe { &*ptr })
+    } else {
+        let pointee = DataDescription::new(address.clone(), std::any::type_name::<T>().into());
+        Value::referenced(address, pointee)
+    };
+    value.with_edge_kind(EdgeKind::Raw)
+}
+
+impl<T> Visualize for *const T
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        Some(raw_pointer_value(*self))
+    }
+}
+
+impl<T> Visualize for *mut T
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        Some(raw_pointer_value(*self as *const T))
+    }
+}
+
+impl<T> Visualize for NonNull<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        Some(raw_pointer_value(self.as_ptr() as *const T))
     }
 }
 
@@ -133,27 +652,1572 @@ where
     }
 
     fn associated_data(&self) -> Option<Vec<DataDescription>> {
-        self.as_ref().map(|x| vec![DataDescription::from(x)])
+        util::with_enum_layout(
+            self,
+            self.as_ref().map(|x| vec![DataDescription::from(x)]),
+            self.is_some().into(),
+            &[0, size_of::<T>()],
+            &[size_of::<(u8,)>(), size_of::<(u8, T)>()],
+            util::has_niche::<T>(),
+            None,
+        )
     }
 }
 
-impl<T> Visualize for Vec<T>
+impl<T, E> Visualize for Result<T, E>
 where
     T: Visualize,
+    E: Visualize,
 {
+    fn data(&self) -> Option<Value> {
+        Some(Value::Owned(
+            match self {
+                Ok(_) => "Ok",
+                Err(_) => "Err",
+            }
+            .into(),
+        ))
+    }
+
     fn associated_data(&self) -> Option<Vec<DataDescription>> {
-        Some(self.iter().map(DataDescription::from).collect())
+        util::with_enum_layout(
+            self,
+            Some(vec![match self {
+                Ok(x) => DataDescription::from(x),
+                Err(e) => DataDescription::from(e),
+            }]),
+            self.is_err().into(),
+            &[size_of::<T>(), size_of::<E>()],
+            &[size_of::<(u8, T)>(), size_of::<(u8, E)>()],
+            util::has_niche::<T>() || util::has_niche::<E>(),
+            None,
+        )
     }
 }
 
-impl<T> Visualize for Box<T>
+impl<T> Visualize for Bound<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        Some(Value::Owned(
+            match self {
+                Bound::Included(_) => "Included",
+                Bound::Excluded(_) => "Excluded",
+                Bound::Unbounded => "Unbounded",
+            }
+            .into(),
+        ))
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        match self {
+            Bound::Included(x) | Bound::Excluded(x) => Some(vec![DataDescription::from(x)]),
+            Bound::Unbounded => None,
+        }
+    }
+}
+
+impl Visualize for Ordering {
+    fn data(&self) -> Option<Value> {
+        Some(Value::Owned(format!("{:?}", self)))
+    }
+}
+
+impl<B> Visualize for Cow<'_, B>
+where
+    B: ?Sized + ToOwned + VisualizeDyn,
+    B::Owned: Visualize,
+{
+    // borrowed data lives elsewhere, so it gets an edge, while owned data is inline like the
+    // data it owns
+    fn data(&self) -> Option<Value> {
+        match self {
+            Cow::Borrowed(borrowed) => {
+                Some(VisualizeDyn::reference(*borrowed).with_edge_kind(EdgeKind::SharedBorrow))
+            }
+            Cow::Owned(owned) => owned.data(),
+        }
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        match self {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(owned) => owned.associated_data(),
+        }
+    }
+}
+
+/// Describe the bounds of a range, labeled like the fields of the range structs
+fn range_rows<T>(start: Option<&T>, end: Option<&T>) -> Option<Vec<DataDescription>>
 where
     T: Visualize,
 {
+    Some(
+        start
+            .map(|start| DataDescription::from(start).with_label("start"))
+            .into_iter()
+            .chain(end.map(|end| DataDescription::from(end).with_label("end")))
+            .collect(),
+    )
+}
+
+macro_rules! impl_visualize_range {
+    ($ty:ident, $operator:literal, |$range:ident| $start:expr, $end:expr) => {
+        // the value is the range operator, between the start and end rows
+        impl<T> Visualize for $ty<T>
+        where
+            T: Visualize,
+        {
+            fn data(&self) -> Option<Value> {
+                Some(Value::Owned($operator.into()))
+            }
+
+            fn associated_data(&self) -> Option<Vec<DataDescription>> {
+                let $range = self;
+                range_rows($start, $end)
+            }
+        }
+    };
+}
+
+impl_visualize_range!(Range, "..", |range| Some(&range.start), Some(&range.end));
+impl_visualize_range!(
+    RangeInclusive,
+    "..=",
+    |range| Some(range.start()),
+    Some(range.end())
+);
+impl_visualize_range!(RangeFrom, "..", |range| Some(&range.start), None);
+impl_visualize_range!(RangeTo, "..", |range| None, Some(&range.end));
+impl_visualize_range!(RangeToInclusive, "..=", |range| None, Some(&range.end));
+
+impl Visualize for RangeFull {
     fn data(&self) -> Option<Value> {
-        Some(Value::referenced(
-            Address::new(self.deref()),
-            DataDescription::from(self.deref()),
+        Some(Value::Owned("..".into()))
+    }
+}
+
+impl<T> Visualize for Vec<T>
+where
+    T: Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        if !options::current().heap_buffers {
+            return Some(self.iter().map(DataDescription::from).collect());
+        }
+
+        // zero sized types are never allocated, but the elements are still worth showing
+        let allocated = self.capacity() > 0 && size_of::<T>() > 0;
+        let buffer = (allocated || !self.is_empty()).then(|| {
+            let buffer_address = Address::from_ptr(self.as_ptr());
+            let elements = self.iter().enumerate().map(|(index, element)| {
+                DataDescription::from(element).with_label(format!("[{}]", index))
+            });
+            let capacity = if allocated {
+                self.capacity()
+            } else {
+                self.len()
+            };
+            elements
+                .chain(describe_unused_slots(&buffer_address, self.len(), capacity))
+                .fold(
+                    DataDescription::new(
+                        buffer_address.clone(),
+                        format!("[{}; {}]", std::any::type_name::<T>(), self.capacity()),
+                    ),
+                    DataDescription::with_associated_data,
+                )
+        });
+
+        Some(describe_heap_buffer_header(
+            &Address::new(self),
+            buffer,
+            self.len(),
+            self.capacity(),
         ))
     }
 }
+
+/// Describe a contiguous segment of a ring buffer, noting the logical indices it holds
+fn describe_segment<T>(
+    owner_address: &Address,
+    label: &str,
+    start: usize,
+    segment: &[T],
+) -> DataDescription
+where
+    T: Visualize,
+{
+    segment.iter().fold(
+        DataDescription::computed(
+            owner_address,
+            label,
+            Value::Owned(format!("[{}..{}]", start, start + segment.len())),
+        ),
+        |data_description, element| {
+            data_description.with_associated_data(DataDescription::from(element))
+        },
+    )
+}
+
+impl<T> Visualize for VecDeque<T>
+where
+    T: Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        if !options::current().vec_deques_as_ring_buffers {
+            return Some(self.iter().map(DataDescription::from).collect());
+        }
+
+        let address = Address::new(self);
+        let (front, back) = self.as_slices();
+        let capacity = DataDescription::computed(
+            &address,
+            "capacity",
+            Value::Owned(self.capacity().to_string()),
+        );
+        let unused = self.capacity() - self.len();
+
+        // the back segment only exists when the deque wraps around the end of its buffer, in which
+        // case it sits at the start of the buffer, and the unused slots sit between the segments.
+        // Otherwise where the front segment sits in the buffer isn't exposed, so the unused slots
+        // could be on either side of it.
+        Some(match back.is_empty() {
+            true => vec![
+                capacity,
+                describe_segment(&address, "front", 0, front),
+                DataDescription::computed(
+                    &address,
+                    "unused",
+                    Value::Owned(format!("{} slots, on either side", unused)),
+                ),
+            ],
+            false => vec![
+                capacity,
+                describe_segment(&address, "back", self.len() - back.len(), back),
+                DataDescription::computed(
+                    &address,
+                    "unused",
+                    Value::Owned(format!("{} slots", unused)),
+                ),
+                describe_segment(&address, "front", 0, front),
+            ],
+        })
+    }
+}
+
+impl<T> Visualize for LinkedList<T>
+where
+    T: Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        let address = Address::new(self);
+        let mut rows = vec![DataDescription::computed(
+            &address,
+            "len",
+            Value::Owned(self.len().to_string()),
+        )];
+
+        // render each node on its own, with the edges between them drawn only, and emit them all
+        // after the edge to the head, so that long lists neither recurse nor nest once per node
+        let mut nodes: Option<RenderedNode> = None;
+        let mut elements = self.iter().peekable();
+        let mut previous = None;
+        while let Some(element) = elements.next() {
+            let element_address = Address::new(element);
+            let mut data_description = DataDescription::for_node(element);
+            if let Some(previous) = previous {
+                data_description =
+                    data_description.with_associated_data(DataDescription::computed(
+                        &element_address,
+                        "prev",
+                        Value::referenced(Address::new(previous), RenderedNode::default())
+                            .with_edge_kind(EdgeKind::Weak),
+                    ));
+            }
+            if let Some(next) = elements.peek() {
+                data_description =
+                    data_description.with_associated_data(DataDescription::computed(
+                        &element_address,
+                        "next",
+                        Value::referenced(Address::new(*next), RenderedNode::default())
+                            .with_edge_kind(EdgeKind::Owning),
+                    ));
+            }
+            let node = RenderedNode::from(data_description);
+            nodes = Some(match nodes {
+                Some(nodes) => nodes.followed_by(node),
+                None => node,
+            });
+            previous = Some(element);
+        }
+
+        if let (Some(head), Some(nodes)) = (self.front(), nodes) {
+            rows.push(DataDescription::computed(
+                &address,
+                "head",
+                Value::referenced(Address::new(head), nodes).with_edge_kind(EdgeKind::Owning),
+            ));
+        }
+        if let Some(tail) = self.back() {
+            rows.push(DataDescription::computed(
+                &address,
+                "tail",
+                Value::referenced(Address::new(tail), RenderedNode::default())
+                    .with_edge_kind(EdgeKind::Weak),
+            ));
+        }
+
+        Some(rows)
+    }
+}
+
+impl<T> Visualize for Box<T>
+where
+    T: ?Sized + VisualizeDyn,
+{
+    fn data(&self) -> Option<Value> {
+        Some(VisualizeDyn::reference(self.deref()).with_edge_kind(EdgeKind::Owning))
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        trait_object_rows(&Address::new(self), self.deref())
+    }
+}
+
+impl<T> Visualize for BTreeSet<T>
+where
+    T: Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        Some(self.iter().map(DataDescription::from).collect())
+    }
+}
+
+impl<T, S> Visualize for HashSet<T, S>
+where
+    T: Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        // sort by rendering so the output doesn't change with the iteration order
+        let mut elements: Vec<_> = self.iter().map(DataDescription::from).collect();
+        elements.sort_by_cached_key(DataDescription::summary);
+        Some(elements)
+    }
+}
+
+/// Describe the element at index of a binary heap's backing array, along with its children in the
+/// implicit binary tree
+fn describe_heap_element<T>(elements: &[T], index: usize) -> DataDescription
+where
+    T: Visualize,
+{
+    let mut data_description = DataDescription::for_node(&elements[index]);
+    let address = data_description.address.clone();
+    data_description = data_description.with_associated_data(DataDescription::computed(
+        &address,
+        "index",
+        Value::Owned(index.to_string()),
+    ));
+
+    for (label, child) in [("left", 2 * index + 1), ("right", 2 * index + 2)] {
+        if let Some(child_element) = elements.get(child) {
+            data_description = data_description.with_associated_data(DataDescription::computed(
+                &address,
+                label,
+                Value::referenced(
+                    Address::new(child_element),
+                    describe_heap_element(elements, child),
+                )
+                .with_edge_kind(EdgeKind::Index),
+            ));
+        }
+    }
+
+    data_description
+}
+
+impl<T> Visualize for BinaryHeap<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        let elements = self.as_slice();
+        (options::current().binary_heaps_as_trees && !elements.is_empty()).then(|| {
+            Value::referenced(
+                Address::new(&elements[0]),
+                describe_heap_element(elements, 0),
+            )
+            .with_edge_kind(EdgeKind::Owning)
+        })
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        (!options::current().binary_heaps_as_trees)
+            .then(|| self.as_slice().iter().map(DataDescription::from).collect())
+    }
+}
+
+/// Describe a map entry
+fn describe_entry<K, V>((key, value): (&K, &V)) -> (DataDescription, DataDescription)
+where
+    K: Visualize,
+    V: Visualize,
+{
+    (DataDescription::from(key), DataDescription::from(value))
+}
+
+/// Create the rows for a map entry, which is its value labeled with its key's rendering
+///
+/// Keys which don't render as a single value, such as structs, get a row of their own before
+/// their value, so that anything they reference is still connected to the map.
+fn entry_rows((key, value): (DataDescription, DataDescription)) -> Vec<DataDescription> {
+    match (&key.value, key.summary()) {
+        (Some(_), Some(key_string)) => vec![value.with_label(key_string)],
+        _ => vec![key.with_label("key"), value.with_label("value")],
+    }
+}
+
+impl<K, V, S> Visualize for HashMap<K, V, S>
+where
+    K: Visualize,
+    V: Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        // sort by the keys' rendering so the output doesn't change with the iteration order
+        let mut entries: Vec<_> = self.iter().map(describe_entry).collect();
+        entries.sort_by_cached_key(|(key, _)| key.summary());
+        Some(entries.into_iter().flat_map(entry_rows).collect())
+    }
+}
+
+impl<K, V> Visualize for BTreeMap<K, V>
+where
+    K: Visualize,
+    V: Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        Some(
+            self.iter()
+                .map(describe_entry)
+                .flat_map(entry_rows)
+                .collect(),
+        )
+    }
+}
+
+macro_rules! impl_visualize_shared_pointer {
+    ($ty:ident) => {
+        impl<T> Visualize for $ty<T>
+        where
+            T: ?Sized + VisualizeDyn,
+        {
+            // every clone references the same allocation, so every clone's edge points at the
+            // same node, labeled with the current reference counts
+            fn data(&self) -> Option<Value> {
+                Some(
+                    VisualizeDyn::reference(self.deref())
+                        .with_edge_kind(EdgeKind::SharedOwnership)
+                        .with_edge_attribute(
+                            "label",
+                            format!(
+                                "strong_count: {}, weak_count: {}",
+                                $ty::strong_count(self),
+                                $ty::weak_count(self)
+                            ),
+                        ),
+                )
+            }
+        }
+    };
+}
+
+impl_visualize_shared_pointer!(Rc);
+impl_visualize_shared_pointer!(Arc);
+
+macro_rules! impl_visualize_weak_pointer {
+    ($module:ident) => {
+        impl<T> Visualize for $module::Weak<T>
+        where
+            T: ?Sized + VisualizeDyn,
+        {
+            // a weak pointer doesn't own its target, so its edge is dashed and doesn't pull the
+            // target into a lower rank, which keeps back-pointers from distorting tree layouts
+            //
+            // the target is upgraded for the duration of the capture, so the strong_count on edges
+            // to the same target captured meanwhile includes this temporary reference
+            fn data(&self) -> Option<Value> {
+                Some(match self.upgrade() {
+                    Some(target) => {
+                        VisualizeDyn::reference(target.deref()).with_edge_kind(EdgeKind::Weak)
+                    }
+                    None => Value::Owned("dangling".into()),
+                })
+            }
+        }
+    };
+}
+
+impl_visualize_weak_pointer!(rc);
+impl_visualize_weak_pointer!(sync);
+
+/// The value of a lazily initialized cell which hasn't been initialized yet
+const UNINIT: &str = "uninit";
+
+macro_rules! impl_visualize_once_cell {
+    ($($ty:ident)+) => {
+        $(
+        impl<T> Visualize for $ty<T>
+        where
+            T: Visualize,
+        {
+            fn data(&self) -> Option<Value> {
+                match self.get() {
+                    Some(_) => None,
+                    None => Some(Value::Owned(UNINIT.into())),
+                }
+            }
+
+            fn associated_data(&self) -> Option<Vec<DataDescription>> {
+                self.get().map(|value| vec![DataDescription::from(value)])
+            }
+        }
+        )+
+    };
+}
+
+impl_visualize_once_cell!(OnceCell OnceLock);
+
+macro_rules! impl_visualize_lazy_cell {
+    ($($ty:ident)+) => {
+        $(
+        // there is no stable way to check whether a lazy cell was forced without forcing it, and
+        // capturing it must not run its initializer, so its contents are never shown
+        impl<T, F> Visualize for $ty<T, F> {
+            fn data(&self) -> Option<Value> {
+                Some(Value::Owned("⟨lazy⟩".into()))
+            }
+        }
+        )+
+    };
+}
+
+impl_visualize_lazy_cell!(LazyCell LazyLock);
+
+impl<T> Visualize for MaybeUninit<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        match options::current().maybe_uninits_assumed_initialized {
+            // SAFETY: the caller of Graph::set_maybe_uninits_assumed_initialized vouches that
+            // this is initialized
+            true => unsafe { self.assume_init_ref() }.data(),
+            false => Some(Value::Owned(format!("{} opaque bytes", size_of::<T>()))),
+        }
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        match options::current().maybe_uninits_assumed_initialized {
+            // SAFETY: as above
+            true => unsafe { self.assume_init_ref() }.associated_data(),
+            false => None,
+        }
+    }
+}
+
+impl<T> Visualize for ManuallyDrop<T>
+where
+    T: ?Sized + Visualize,
+{
+    // the data is shown as if it weren't wrapped, with a row marking that it isn't dropped
+    fn data(&self) -> Option<Value> {
+        self.deref().data()
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        let marker =
+            DataDescription::computed(&Address::new(self), "drop", Value::Owned("manual".into()));
+        Some(
+            self.deref()
+                .associated_data()
+                .into_iter()
+                .flatten()
+                .chain(std::iter::once(marker))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Visualize for PhantomData<T>
+where
+    T: ?Sized,
+{
+    fn data(&self) -> Option<Value> {
+        Some(Value::Owned(std::any::type_name::<T>().into()))
+    }
+}
+
+/// The value shown in place of the contents of a cell which can't be read without blocking or
+/// panicking
+const LOCKED: &str = "⟨locked⟩";
+
+/// Describe the contents of a cell, or [LOCKED] if they can't be read
+fn describe_cell_contents<T>(cell_address: Address, contents: Option<&T>) -> Vec<DataDescription>
+where
+    T: Visualize,
+{
+    vec![match contents {
+        Some(contents) => DataDescription::from(contents),
+        None => DataDescription::computed(&cell_address, "value", Value::Owned(LOCKED.into())),
+    }]
+}
+
+impl<T> Visualize for Cell<T>
+where
+    T: Copy + Visualize,
+{
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        // describe a copy, since describing the contents in place could call Cell::set on this
+        // cell while they're borrowed, and show it at the address of the contents
+        let contents = self.get();
+        Some(vec![DataDescription::from(&contents).relocated(
+            &Address::new(&contents),
+            size_of::<T>(),
+            &Address::from_ptr(self.as_ptr()),
+        )])
+    }
+}
+
+impl<T> Visualize for RefCell<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        let state = match (self.try_borrow_mut().is_ok(), self.try_borrow().is_ok()) {
+            (true, _) => "unborrowed",
+            (false, true) => "borrowed",
+            (false, false) => "mutably borrowed",
+        };
+        Some(Value::Owned(state.into()))
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        let contents = self.try_borrow().ok();
+        Some(describe_cell_contents(
+            Address::new(self),
+            contents.as_deref(),
+        ))
+    }
+}
+
+/// Describe the state of a lock, noting whether it's poisoned
+fn lock_state(state: &str, poisoned: bool) -> Option<Value> {
+    Some(Value::Owned(match poisoned {
+        true => format!("{}, poisoned", state),
+        false => state.into(),
+    }))
+}
+
+impl<T> Visualize for Mutex<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        let state = match self.try_lock() {
+            Err(TryLockError::WouldBlock) => "locked",
+            _ => "unlocked",
+        };
+        lock_state(state, self.is_poisoned())
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        // a poisoned lock's contents are still readable
+        let contents = match self.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        };
+        Some(describe_cell_contents(
+            Address::new(self),
+            contents.as_deref(),
+        ))
+    }
+}
+
+impl<T> Visualize for RwLock<T>
+where
+    T: Visualize,
+{
+    fn data(&self) -> Option<Value> {
+        let state = match (self.try_write(), self.try_read()) {
+            (Err(TryLockError::WouldBlock), Err(TryLockError::WouldBlock)) => "write locked",
+            (Err(TryLockError::WouldBlock), _) => "read locked",
+            _ => "unlocked",
+        };
+        lock_state(state, self.is_poisoned())
+    }
+
+    fn associated_data(&self) -> Option<Vec<DataDescription>> {
+        // a poisoned lock's contents are still readable
+        let contents = match self.try_read() {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        };
+        Some(describe_cell_contents(
+            Address::new(self),
+            contents.as_deref(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Get the string of an owned value
+    fn owned(value: &Option<Value>) -> &str {
+        match value {
+            Some(Value::Owned(data)) => data,
+            _ => panic!("expected an owned value"),
+        }
+    }
+
+    #[test]
+    fn test_rc_clones_share_target() {
+        let rc = Rc::new(5u8);
+        let clone = Rc::clone(&rc);
+        let _weak = Rc::downgrade(&rc);
+        let target_address = Address::new(rc.deref());
+
+        for pointer in [&rc, &clone] {
+            let data_description = DataDescription::from(pointer);
+            let references = data_description.render_references(&data_description.address);
+            assert!(references.starts_with(&format!(
+                "\"{0}\":\"{0}-value\" -> \"{1}\":\"{1}-address\" [dir=\"both\", arrowtail=\"odiamond\", label=\"strong_count: 2, weak_count: 1\"]\n",
+                data_description.address, target_address
+            )));
+        }
+    }
+
+    #[test]
+    fn test_weak() {
+        let rc = Rc::new(5u8);
+        let weak = Rc::downgrade(&rc);
+        let data_description = DataDescription::from(&weak);
+        assert_eq!(
+            data_description.render_references(&data_description.address),
+            format!(
+                "\"{0}\":\"{0}-value\" -> \"{1}\":\"{1}-address\" [style=\"dashed\", constraint=\"false\"]\n{2}\n",
+                data_description.address,
+                Address::new(rc.deref()),
+                rc.deref().render_node()
+            )
+        );
+
+        drop(rc);
+        let data_description = DataDescription::from(&weak);
+        assert_eq!(data_description.render_table_row(), format!("<TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>alloc::rc::Weak&lt;u8&gt;</B></TD><TD PORT=\"{0}-value\">dangling</TD></TR>", data_description.address));
+    }
+
+    #[test]
+    fn test_weak_cycle() {
+        struct Node {
+            me: rc::Weak<Node>,
+        }
+
+        impl Visualize for Node {
+            fn associated_data(&self) -> Option<Vec<DataDescription>> {
+                Some(vec![DataDescription::from(&self.me).with_label("me")])
+            }
+        }
+
+        let node = Rc::new_cyclic(|me| Node { me: me.clone() });
+        let node_address = Address::new(node.deref());
+        let rendered = node.render_node().inner().to_string();
+        assert_eq!(
+            rendered
+                .matches(&format!("\"{}\" [label=", node_address))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_ref_cell() {
+        let cell = RefCell::new(5u8);
+        let address = Address::new(&cell);
+        let inner_address = Address::new(&*cell.borrow());
+        assert_eq!(DataDescription::from(&cell).render_table_row(), format!("<TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>core::cell::RefCell&lt;u8&gt;</B></TD><TD PORT=\"{0}-value\">unborrowed</TD><TD PORT=\"{0}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{1}-address\"><I>{1}</I></TD><TD PORT=\"{1}-type\"><B>u8</B></TD><TD PORT=\"{1}-value\">5</TD></TR></TABLE></TD></TR>", address, inner_address));
+
+        let borrow = cell.borrow();
+        assert_eq!(owned(&DataDescription::from(&cell).value), "borrowed");
+        drop(borrow);
+
+        let _borrow_mut = cell.borrow_mut();
+        assert_eq!(DataDescription::from(&cell).render_table_row(), format!("<TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>core::cell::RefCell&lt;u8&gt;</B></TD><TD PORT=\"{0}-value\">mutably borrowed</TD><TD PORT=\"{0}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{0}-value-label\">value</TD><TD PORT=\"{0}-value-value\">⟨locked⟩</TD></TR></TABLE></TD></TR>", address));
+    }
+
+    #[test]
+    fn test_mutex() {
+        let mutex = Mutex::new(5u8);
+        assert_eq!(owned(&DataDescription::from(&mutex).value), "unlocked");
+
+        let guard = mutex.lock().unwrap();
+        let data_description = DataDescription::from(&mutex);
+        assert_eq!(owned(&data_description.value), "locked");
+        assert_eq!(
+            owned(
+                &data_description
+                    .associated_data_descriptions
+                    .as_ref()
+                    .unwrap()[0]
+                    .value
+            ),
+            LOCKED
+        );
+        drop(guard);
+
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = mutex.lock().unwrap();
+            panic!("poison the mutex");
+        });
+        let data_description = DataDescription::from(&mutex);
+        assert_eq!(owned(&data_description.value), "unlocked, poisoned");
+        assert_eq!(
+            owned(
+                &data_description
+                    .associated_data_descriptions
+                    .as_ref()
+                    .unwrap()[0]
+                    .value
+            ),
+            "5"
+        );
+    }
+
+    #[test]
+    fn test_cell() {
+        let cell = Cell::new((1u8, 2u16));
+        let data_description = DataDescription::from(&cell);
+        let contents = &data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap()[0];
+        assert_eq!(
+            contents.address.to_string(),
+            Address::from_ptr(cell.as_ptr()).to_string()
+        );
+        let fields = contents.associated_data_descriptions.as_ref().unwrap();
+        // SAFETY: nothing sets the cell while its contents are borrowed here
+        assert_eq!(
+            fields[0].address.to_string(),
+            Address::new(&unsafe { &*cell.as_ptr() }.0).to_string()
+        );
+        assert_eq!(owned(&fields[1].value), "2");
+    }
+
+    #[test]
+    fn test_hash_map() {
+        let referenced = String::from("referenced");
+        let mut map = HashMap::new();
+        let _ = map.insert(2u8, &referenced);
+        let _ = map.insert(1u8, &referenced);
+        let data_description = DataDescription::from(&map);
+
+        let rows = data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap();
+        let labels: Vec<_> = rows.iter().map(|row| row.label_string.clone()).collect();
+        assert_eq!(labels, [Some("1".into()), Some("2".into())]);
+        assert_eq!(
+            data_description
+                .render_references(&data_description.address)
+                .matches(&format!("-> \"{}\"", Address::new(&referenced)))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_hash_map_str_keys() {
+        let keys = [
+            "delta", "alpha", "echo", "charlie", "bravo", "golf", "foxtrot", "hotel",
+        ];
+        let map: HashMap<_, _> = keys.iter().map(|key| (*key, key.len())).collect();
+        let data_description = DataDescription::from(&map);
+
+        let rows = data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap();
+        let labels: Vec<_> = rows.iter().map(|row| row.label_string.as_deref()).collect();
+        let mut sorted_keys: Vec<_> = keys.iter().map(|key| Some(*key)).collect();
+        sorted_keys.sort();
+        assert_eq!(labels, sorted_keys);
+    }
+
+    #[test]
+    fn test_b_tree_map_compound_key() {
+        let map: BTreeMap<_, _> = vec![((1u8, 'a'), true)].into_iter().collect();
+        let data_description = DataDescription::from(&map);
+
+        let rows = data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap();
+        let labels: Vec<_> = rows.iter().map(|row| row.label_string.clone()).collect();
+        assert_eq!(labels, [Some("key".into()), Some("value".into())]);
+    }
+
+    #[test]
+    fn test_hash_set() {
+        let set: HashSet<_> = vec![3u8, 1, 2].into_iter().collect();
+        let data_description = DataDescription::from(&set);
+        let rows = data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap();
+        let elements: Vec<_> = rows.iter().map(|row| owned(&row.value)).collect();
+        assert_eq!(elements, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_hash_set_str() {
+        let mut keys = [
+            "delta", "alpha", "echo", "charlie", "bravo", "golf", "foxtrot", "hotel",
+        ];
+        let set: HashSet<_> = keys.iter().copied().collect();
+        let data_description = DataDescription::from(&set);
+        let rows = data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap();
+        let targets: Vec<_> = rows
+            .iter()
+            .map(|row| match &row.value {
+                Some(Value::Referenced(address, _)) => address.to_string(),
+                _ => panic!("expected a reference"),
+            })
+            .collect();
+
+        keys.sort_unstable();
+        let sorted: Vec<_> = keys
+            .iter()
+            .map(|key| Address::new(*key).to_string())
+            .collect();
+        assert_eq!(targets, sorted);
+    }
+
+    #[test]
+    fn test_binary_heap_as_tree() {
+        let heap: BinaryHeap<_> = vec![1u8, 2, 3].into_iter().collect();
+        let elements = heap.as_slice();
+
+        let as_list = DataDescription::from(&heap);
+        assert!(as_list.value.is_none());
+        assert_eq!(
+            as_list.associated_data_descriptions.as_ref().unwrap().len(),
+            3
+        );
+
+        let references = crate::Graph::new()
+            .set_binary_heaps_as_trees(true)
+            .add_node(&heap)
+            .render();
+        for child in 1..3 {
+            assert!(references.contains(&format!(
+                "\"{0}\":\"{0}-{1}-value\" -> \"{2}\":\"{2}-address\"",
+                Address::new(&elements[0]),
+                ["left", "right"][child - 1],
+                Address::new(&elements[child])
+            )));
+        }
+    }
+
+    #[test]
+    fn test_vec_deque_as_ring_buffer() {
+        let mut deque = VecDeque::with_capacity(4);
+        deque.extend([1u8, 2, 3, 4].iter());
+        let _ = deque.pop_front();
+        let _ = deque.pop_front();
+        deque.push_back(5);
+        let capacity = deque.capacity();
+
+        let as_list = DataDescription::from(&deque);
+        let rows = as_list.associated_data_descriptions.as_ref().unwrap();
+        let elements: Vec<_> = rows.iter().map(|row| owned(&row.value)).collect();
+        assert_eq!(elements, ["3", "4", "5"]);
+
+        let rendered = crate::Graph::new()
+            .set_vec_deques_as_ring_buffers(true)
+            .add_node(&deque)
+            .render();
+        let address = Address::new(&deque);
+        assert!(rendered.contains(&format!(
+            "<TD PORT=\"{0}-capacity-label\">capacity</TD><TD PORT=\"{0}-capacity-value\">{1}</TD>",
+            address, capacity
+        )));
+        assert!(rendered.contains(&format!(
+            "<TD PORT=\"{0}-unused-label\">unused</TD><TD PORT=\"{0}-unused-value\">{1} slots</TD>",
+            address,
+            capacity - 3
+        )));
+    }
+
+    #[test]
+    fn test_vec_deque_as_ring_buffer_after_pop_front() {
+        let mut deque = VecDeque::<u8>::with_capacity(8);
+        deque.extend([1u8, 2, 3, 4].iter());
+        let _ = deque.pop_front();
+        let capacity = deque.capacity();
+
+        let data_description = options::with(
+            &options::Options {
+                vec_deques_as_ring_buffers: true,
+                ..Default::default()
+            },
+            || DataDescription::from(&deque),
+        );
+        let rows = data_description
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap();
+        let rows: Vec<_> = rows
+            .iter()
+            .map(|row| (row.label_string.as_deref().unwrap(), owned(&row.value)))
+            .collect();
+        let unused = format!("{} slots, on either side", capacity - 3);
+        assert_eq!(
+            rows,
+            [
+                ("capacity", capacity.to_string().as_str()),
+                ("front", "[0..3]"),
+                ("unused", unused.as_str()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_linked_list() {
+        let list: LinkedList<_> = vec![1u8, 2, 3].into_iter().collect();
+        let addresses: Vec<_> = list.iter().map(Address::new).collect();
+        let rendered = crate::Graph::from(&list).render();
+
+        // every element is its own node, linked to its neighbours
+        for (index, address) in addresses.iter().enumerate() {
+            assert_eq!(
+                rendered
+                    .matches(&format!("\"{}\" [label=", address))
+                    .count(),
+                1
+            );
+            if let Some(next) = addresses.get(index + 1) {
+                assert!(rendered.contains(&format!(
+                    "\"{0}\":\"{0}-next-value\" -> \"{1}\":\"{1}-address\" [dir=\"both\", arrowtail=\"diamond\"]\n",
+                    address, next
+                )));
+                assert!(rendered.contains(&format!(
+                    "\"{1}\":\"{1}-prev-value\" -> \"{0}\":\"{0}-address\" [style=\"dashed\", constraint=\"false\"]\n",
+                    address, next
+                )));
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_linked_list() {
+        // each node is rendered once on its own, rather than again inside every node before it,
+        // which would take quadratic time
+        let list: LinkedList<_> = (0..10_000u32).collect();
+        let rendered = crate::Graph::from(&list).render();
+        assert_eq!(rendered.matches(" [label=<").count(), 10_001);
+    }
+
+    #[test]
+    fn test_vec_heap_buffer() {
+        let mut vec = Vec::with_capacity(4);
+        vec.push(7u8);
+        let address = Address::new(&vec);
+        let buffer_address = Address::new(&vec[0]);
+
+        let rendered = crate::Graph::new()
+            .set_heap_buffers(true)
+            .add_node(&vec)
+            .render();
+        assert!(rendered.contains(&format!(
+            "\"{0}\":\"{0}-ptr-value\" -> \"{1}\":\"{1}-address\" [dir=\"both\", arrowtail=\"diamond\"]\n",
+            address, buffer_address
+        )));
+        assert!(rendered.contains(&format!(
+            "<TD PORT=\"{0}-len-label\">len</TD><TD PORT=\"{0}-len-value\">1</TD>",
+            address
+        )));
+        assert!(rendered.contains(&format!(
+            "<TD PORT=\"{0}-label\">[0]</TD><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>u8</B></TD><TD PORT=\"{0}-value\">7</TD>",
+            buffer_address
+        )));
+        assert_eq!(rendered.matches("BGCOLOR=\"lightgrey\">[").count(), 3);
+    }
+
+    #[test]
+    fn test_string_heap_buffer() {
+        let string = String::from("hé");
+        let buffer_address = Address::from_ptr(string.as_ptr());
+
+        let rendered = crate::Graph::new()
+            .set_heap_buffers(true)
+            .add_node(&string)
+            .render();
+        assert!(rendered.contains(&format!(
+            "<TD PORT=\"{0}-[0..1]-label\">[0..1]</TD><TD PORT=\"{0}-[0..1]-value\">68 'h'</TD>",
+            buffer_address
+        )));
+        assert!(rendered.contains(&format!(
+            "<TD PORT=\"{0}-[1..3]-label\">[1..3]</TD><TD PORT=\"{0}-[1..3]-value\">c3 a9 'é'</TD>",
+            buffer_address
+        )));
+    }
+
+    #[test]
+    fn test_raw_pointers() {
+        let target = 5u8;
+        let ptr: *const u8 = &target;
+        let null: *mut u8 = std::ptr::null_mut();
+        let target_address = Address::new(&target);
+
+        assert_eq!(owned(&DataDescription::from(&null).value), NULL);
+
+        let not_followed = crate::Graph::from(&ptr).render();
+        assert!(not_followed.contains(&format!(
+            "<TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>u8</B></TD></TR>",
+            target_address
+        )));
+
+        let followed = unsafe { crate::Graph::new().add_node_following_raw_pointers(&ptr) }
+            .add_node(&NonNull::from(&target))
+            .render();
+        assert_eq!(
+            followed
+                .matches(&format!("<TD PORT=\"{}-value\">5</TD>", target_address))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_trait_objects() {
+        let target = 5u8;
+        let boxed: Box<dyn VisualizeDyn> = Box::new(7u16);
+        let reference: &dyn VisualizeDyn = &target;
+        let boxed_data = DataDescription::from(&boxed);
+        let reference_data = DataDescription::from(&reference);
+
+        let boxed_pointer: *const dyn VisualizeDyn = boxed.deref();
+        match &boxed_data.value {
+            Some(Value::Referenced(address, ..)) => {
+                assert_eq!(
+                    address.to_string(),
+                    Address::from_ptr(boxed_pointer as *const u8).to_string()
+                )
+            }
+            _ => panic!("a Box<dyn VisualizeDyn> should reference its data"),
+        }
+
+        let rows: Vec<_> = reference_data
+            .associated_data_descriptions
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|row| {
+                (
+                    row.label_string.clone().unwrap(),
+                    owned(&row.value).to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(rows[0], ("concrete type".to_string(), "u8".to_string()));
+        assert_eq!(
+            rows[1],
+            ("data".to_string(), Address::new(&target).to_string())
+        );
+        assert_eq!(rows.len(), 3);
+
+        // references to sized data are plain references
+        assert!(DataDescription::from(&&target)
+            .associated_data_descriptions
+            .is_none());
+    }
+
+    #[test]
+    fn test_unsized() {
+        let string = "hi";
+        let boxed_slice: Box<[u8]> = vec![1, 2].into_boxed_slice();
+        let shared_str: Rc<str> = Rc::from("shared");
+        let shared_slice: Arc<[u8]> = Arc::from(vec![3]);
+
+        let str_data = DataDescription::from(string);
+        assert_eq!(str_data.type_string, "str");
+        assert!(str_data.address.to_string().starts_with("0x"));
+        assert_eq!(str_data.address.value(), Some(string.as_ptr() as usize));
+        assert_eq!(owned(&str_data.value), "hi");
+
+        let slice_data = DataDescription::from(boxed_slice.deref());
+        assert_eq!(slice_data.type_string, "[u8]");
+        assert_eq!(
+            slice_data
+                .associated_data_descriptions
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(|element| owned(&element.value))
+                .collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+
+        let rendered = crate::Graph::new()
+            .add_node(&string)
+            .add_node(&boxed_slice)
+            .add_node(&shared_str)
+            .add_node(&shared_slice)
+            .render();
+        for (target, value) in &[
+            (Address::new(string), "hi"),
+            (Address::new(shared_str.deref()), "shared"),
+        ] {
+            assert!(rendered.contains(&format!("<TD PORT=\"{0}-value\">{1}</TD>", target, value)));
+        }
+        for target in &[
+            Address::new(boxed_slice.deref()),
+            Address::new(shared_slice.deref()),
+        ] {
+            assert!(rendered.contains(&format!("-> \"{0}\":\"{0}-address\"", target)));
+        }
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(owned(&DataDescription::from(&0.1f64).value), "0.1");
+        assert_eq!(
+            owned(&DataDescription::from(&NonZeroU32::new(3).unwrap()).value),
+            "3"
+        );
+        assert_eq!(owned(&DataDescription::from(&Wrapping(4u8)).value), "4");
+        assert_eq!(owned(&DataDescription::from(&Saturating(5i8)).value), "5");
+        assert_eq!(
+            owned(&DataDescription::from(&atomic::AtomicUsize::new(6)).value),
+            "6"
+        );
+        assert_eq!(
+            owned(&DataDescription::from(&AtomicBool::new(true)).value),
+            "true"
+        );
+        assert!(DataDescription::from(&1.5f32)
+            .associated_data_descriptions
+            .is_none());
+    }
+
+    #[test]
+    fn test_float_bits() {
+        let float = -1.5f32;
+        let infinity = f64::INFINITY;
+        let rendered = crate::Graph::new()
+            .set_float_bits(true)
+            .add_node(&float)
+            .add_node(&infinity)
+            .render();
+
+        for (address, label, value) in &[
+            (Address::new(&float), "sign", "1 (-)"),
+            (Address::new(&float), "exponent", "01111111 (2^0)"),
+            (Address::new(&float), "mantissa", "0x400000"),
+            (Address::new(&infinity), "sign", "0 (+)"),
+            (
+                Address::new(&infinity),
+                "exponent",
+                "11111111111 (infinite)",
+            ),
+            (Address::new(&infinity), "mantissa", "0x0000000000000"),
+        ] {
+            assert!(
+                rendered.contains(&format!(
+                    "<TD PORT=\"{0}-{1}-value\">{2}</TD>",
+                    address, label, value
+                )),
+                "{} {} should be {}",
+                address,
+                label,
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_option_and_result_enum_layouts() {
+        let layout_rows = |data_description: DataDescription| {
+            data_description
+                .associated_data_descriptions
+                .as_ref()
+                .map(|rows| {
+                    rows.iter()
+                        .filter(|row| row.computed)
+                        .map(|row| (row.label_string.clone().unwrap(), row.summary().unwrap()))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        let options = options::Options {
+            enum_layouts: true,
+            ..Default::default()
+        };
+
+        let boxed = Some(Box::new(1u8));
+        assert_eq!(
+            layout_rows(options::with(&options, || DataDescription::from(&boxed))),
+            [
+                ("discriminant".to_string(), "1".to_string()),
+                (
+                    "size".into(),
+                    format!("{0} bytes, largest variant {0} bytes", size_of::<usize>())
+                ),
+                ("niche".into(), "in use".into()),
+            ]
+        );
+
+        let err: Result<u8, u8> = Err(5);
+        assert_eq!(
+            layout_rows(options::with(&options, || DataDescription::from(&err))),
+            [
+                ("discriminant".to_string(), "1".to_string()),
+                ("size".into(), "2 bytes, largest variant 1 bytes".into()),
+                ("niche".into(), "not in use".into()),
+            ]
+        );
+
+        // without the option, only the payload is described
+        assert!(layout_rows(DataDescription::from(&boxed)).is_empty());
+    }
+
+    #[test]
+    fn test_result_bound_and_ordering() {
+        let ok: Result<u8, String> = Ok(1);
+        let err: Result<u8, String> = Err("oops".into());
+        let bound: Bound<u8> = Bound::Excluded(2);
+
+        for (data, variant, payload) in &[
+            (DataDescription::from(&ok), "Ok", Some("1")),
+            (DataDescription::from(&err), "Err", Some("oops")),
+            (DataDescription::from(&bound), "Excluded", Some("2")),
+            (
+                DataDescription::from(&Bound::<u8>::Unbounded),
+                "Unbounded",
+                None,
+            ),
+        ] {
+            assert_eq!(owned(&data.value), *variant);
+            assert_eq!(
+                data.associated_data_descriptions
+                    .as_ref()
+                    .map(|payload| owned(&payload[0].value)),
+                *payload
+            );
+        }
+        assert_eq!(owned(&DataDescription::from(&Ordering::Less).value), "Less");
+    }
+
+    #[test]
+    fn test_cow() {
+        let text = String::from("borrowed");
+        let borrowed: Cow<str> = Cow::Borrowed(&text);
+        let owned_cow: Cow<str> = Cow::Owned("owned".into());
+
+        match &DataDescription::from(&borrowed).value {
+            Some(Value::Referenced(address, ..)) => {
+                assert_eq!(address.to_string(), Address::new(text.as_str()).to_string())
+            }
+            _ => panic!("a borrowed Cow should reference the borrowed data"),
+        }
+        assert_eq!(owned(&DataDescription::from(&owned_cow).value), "owned");
+    }
+
+    #[test]
+    fn test_ranges() {
+        let rows = |data: DataDescription| {
+            data.associated_data_descriptions
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(|row| {
+                    format!(
+                        "{}={}",
+                        row.label_string.as_ref().unwrap(),
+                        owned(&row.value)
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let range = DataDescription::from(&(1u8..3));
+        assert_eq!(owned(&range.value), "..");
+        assert_eq!(rows(range), vec!["start=1", "end=3"]);
+        assert_eq!(
+            rows(DataDescription::from(&(1u8..=3))),
+            vec!["start=1", "end=3"]
+        );
+        assert_eq!(rows(DataDescription::from(&(..=3u8))), vec!["end=3"]);
+        assert_eq!(owned(&DataDescription::from(&(..)).value), "..");
+    }
+
+    #[test]
+    fn test_ffi_strings() {
+        let path = PathBuf::from("/tmp/file.txt");
+        let c_string = CString::new(b"caf\xc3\xa9 \xff!".to_vec()).unwrap();
+
+        assert_eq!(owned(&DataDescription::from(&path).value), "/tmp/file.txt");
+        assert_eq!(
+            owned(&DataDescription::from(path.as_path()).value),
+            "/tmp/file.txt"
+        );
+        assert_eq!(
+            owned(&DataDescription::from(&OsString::from("os")).value),
+            "os"
+        );
+        assert_eq!(
+            owned(&DataDescription::from(&c_string).value),
+            "café \\xff!"
+        );
+
+        let rendered = crate::Graph::new()
+            .set_c_string_nuls(true)
+            .add_node(&c_string)
+            .render();
+        assert!(rendered.contains("café \\xff!\\0</TD>"));
+    }
+
+    #[test]
+    fn test_net_addresses() {
+        let socket: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        assert_eq!(
+            owned(&DataDescription::from(&socket).value),
+            "127.0.0.1:8080"
+        );
+        assert_eq!(
+            owned(&DataDescription::from(&Ipv6Addr::LOCALHOST).value),
+            "::1"
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        assert_eq!(
+            owned(&DataDescription::from(&Duration::from_millis(1500)).value),
+            "1.5s"
+        );
+
+        for (time, rendered) in &[
+            (UNIX_EPOCH, "1970-01-01T00:00:00Z"),
+            (
+                UNIX_EPOCH + Duration::new(951_827_696, 250_000_000),
+                "2000-02-29T12:34:56.25Z",
+            ),
+            (
+                UNIX_EPOCH - Duration::from_millis(500),
+                "1969-12-31T23:59:59.5Z",
+            ),
+        ] {
+            assert_eq!(owned(&DataDescription::from(time).value), *rendered);
+        }
+
+        let reference = Instant::now();
+        let options = options::Options {
+            reference_instant: Some(reference),
+            ..Default::default()
+        };
+        for (instant, rendered) in &[
+            (
+                reference + Duration::from_secs(3600),
+                "graph created + 3600s",
+            ),
+            (
+                reference - Duration::from_millis(1500),
+                "graph created - 1.5s",
+            ),
+        ] {
+            let data_description = options::with(&options, || DataDescription::from(instant));
+            assert_eq!(owned(&data_description.value), *rendered);
+        }
+    }
+
+    #[test]
+    fn test_lazy_and_wrapper_types() {
+        let once_cell = OnceCell::new();
+        assert_eq!(owned(&DataDescription::from(&once_cell).value), UNINIT);
+        once_cell.set(3u8).unwrap();
+        let set = DataDescription::from(&once_cell);
+        assert!(set.value.is_none());
+        assert_eq!(
+            owned(&set.associated_data_descriptions.as_ref().unwrap()[0].value),
+            "3"
+        );
+
+        let lazy = LazyLock::new(|| -> u8 { panic!("capturing must not force a LazyLock") });
+        assert_eq!(owned(&DataDescription::from(&lazy).value), "⟨lazy⟩");
+
+        let manually_drop = ManuallyDrop::new(4u16);
+        let manually_drop_data = DataDescription::from(&manually_drop);
+        assert_eq!(owned(&manually_drop_data.value), "4");
+        assert_eq!(
+            owned(
+                &manually_drop_data
+                    .associated_data_descriptions
+                    .as_ref()
+                    .unwrap()[0]
+                    .value
+            ),
+            "manual"
+        );
+
+        assert_eq!(
+            owned(&DataDescription::from(&PhantomData::<str>).value),
+            "str"
+        );
+    }
+
+    #[test]
+    fn test_maybe_uninit() {
+        let maybe_uninit = MaybeUninit::new(5u32);
+        let value_cell = format!("<TD PORT=\"{}-value\">", Address::new(&maybe_uninit));
+
+        let opaque = crate::Graph::from(&maybe_uninit).render();
+        assert!(opaque.contains(&format!("{}4 opaque bytes</TD>", value_cell)));
+
+        let assumed = unsafe { crate::Graph::new().set_maybe_uninits_assumed_initialized(true) }
+            .add_node(&maybe_uninit)
+            .render();
+        assert!(assumed.contains(&format!("{}5</TD>", value_cell)));
+    }
+
+    #[test]
+    fn test_mutable_references() {
+        let mut target = 5u8;
+        let mut slice = [1u8, 2];
+        let target_address = Address::new(&target);
+        let slice_address = Address::new(&slice[..]);
+        let unique = &mut target;
+        let unique_slice: &mut [u8] = &mut slice;
+
+        let rendered = crate::Graph::new()
+            .add_node(&unique)
+            .add_node(&unique_slice)
+            .render();
+        for address in &[target_address, slice_address] {
+            assert!(rendered.contains(&format!(
+                "-> \"{0}\":\"{0}-address\" [style=\"bold\"]",
+                address
+            )));
+        }
+    }
+}
@@ -23,20 +23,32 @@
 )]
 
 mod address;
+mod attributes;
 mod constants;
 mod data_description;
+mod edge;
 mod graph;
 mod impls;
 mod node;
+mod options;
+mod style;
+mod theme;
 #[doc(hidden)]
 pub mod util;
 mod visualize;
 
 pub use crate::address::Address;
+pub use crate::attributes::{EdgeOrdering, RankDir, Splines};
 pub use crate::data_description::DataDescription;
 pub use crate::data_description::Value;
+pub use crate::edge::Edge;
+pub use crate::edge::EdgeKind;
+pub use crate::edge::EdgeSelector;
 pub use crate::graph::Graph;
+pub use crate::style::Style;
+pub use crate::theme::{CellStyle, Theme};
 pub use crate::visualize::Visualize;
+pub use crate::visualize::VisualizeDyn;
 
 #[cfg(feature = "derive")]
 pub use vizz_derive::Visualize;
@@ -1,32 +1,75 @@
-use crate::{util, DataDescription};
+use crate::{util, DataDescription, Edge};
 
 #[derive(Debug, Clone, Default)]
-/// A string containing a graphviz graph node
-pub struct RenderedNode(String);
+/// A string containing a graphviz graph node, and the edge drawn to it when it is referenced
+pub struct RenderedNode {
+    node: String,
+    edge: Edge,
+    summary: Option<String>,
+}
 
 impl RenderedNode {
     /// Get the string value for this node
     pub fn inner(&self) -> &str {
-        &self.0
+        &self.node
+    }
+
+    /// Get the edge drawn to this node when it is referenced
+    pub fn edge(&self) -> &Edge {
+        &self.edge
+    }
+
+    /// A rendering of the value of the data at the root of this node, see
+    /// [DataDescription::summary]
+    pub(crate) fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    /// Emit the nodes of other after this one, keeping this node's edge and summary
+    pub(crate) fn followed_by(mut self, other: RenderedNode) -> Self {
+        self.node.push('\n');
+        self.node.push_str(&other.node);
+        self
+    }
+
+    /// Change the edge drawn to this node when it is referenced
+    pub(crate) fn map_edge(self, f: impl FnOnce(Edge) -> Edge) -> Self {
+        Self {
+            edge: f(self.edge),
+            ..self
+        }
     }
 }
 
 impl std::fmt::Display for RenderedNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(&self.0, f)
+        std::fmt::Display::fmt(&self.node, f)
     }
 }
 
 impl From<DataDescription> for RenderedNode {
     fn from(data_description: DataDescription) -> Self {
-        Self(format!(
-            r#"  node [shape=plaintext]
-    "{}" [label=<{}>];
+        let shape = match &data_description.style.shape {
+            Some(shape) => format!(", shape=\"{}\"", util::dot_escape(shape)),
+            None => String::new(),
+        };
+        // only the value of the root is kept, since the data it owns could be arbitrarily large
+        let summary = match data_description.value {
+            Some(_) => data_description.summary(),
+            None => None,
+        };
+        Self {
+            node: format!(
+                r#"    "{}" [label=<{}>{}];
     {}"#,
-            data_description.address,
-            util::render_table(std::iter::once(data_description.render_table_row())),
-            data_description.render_references(&data_description.address)
-        ))
+                data_description.address,
+                util::render_table(std::iter::once(data_description.render_table_row())),
+                shape,
+                data_description.render_references(&data_description.address)
+            ),
+            edge: Edge::default(),
+            summary,
+        }
     }
 }
 
@@ -37,6 +80,10 @@ where
     T: Into<String>,
 {
     fn from(t: T) -> Self {
-        Self(t.into())
+        Self {
+            node: t.into(),
+            edge: Edge::default(),
+            summary: None,
+        }
     }
 }
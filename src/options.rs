@@ -0,0 +1,89 @@
+use std::cell::RefCell;
+use std::time::Instant;
+
+use crate::edge::EdgeSelector;
+use crate::theme::Theme;
+
+#[derive(Debug, Clone, Default)]
+/// Options changing how data is described, set on a [Graph] and read by [Visualize] impls while
+/// the graph's nodes are being rendered
+///
+/// [Graph]: crate::Graph
+/// [Visualize]: crate::Visualize
+pub(crate) struct Options {
+    /// Render a [std::collections::BinaryHeap] as the binary tree its backing array represents,
+    /// rather than as a list
+    pub(crate) binary_heaps_as_trees: bool,
+    /// Render a [std::collections::VecDeque] as the segments of its ring buffer in memory order,
+    /// rather than as a list in logical order
+    pub(crate) vec_deques_as_ring_buffers: bool,
+    /// Render a [Vec] or [String] as its (ptr, len, capacity) header with an edge to its heap
+    /// buffer, rather than as its contents
+    pub(crate) heap_buffers: bool,
+    /// Render the size and alignment of all data, and the data inside of other data in the order
+    /// it sits in memory, with its offset and rows for the gaps between it
+    pub(crate) memory_layout: bool,
+    /// Render the discriminant, size and niche use of derived enums
+    pub(crate) enum_layouts: bool,
+    /// Render a hex and ASCII dump of the bytes of the data at the root of each graph node
+    pub(crate) hex_dumps: bool,
+    /// Render the sign, exponent and mantissa bits of [f32] and [f64] next to their value
+    pub(crate) float_bits: bool,
+    /// Render the NUL byte at the end of a [std::ffi::CStr] or [std::ffi::CString]
+    pub(crate) c_string_nuls: bool,
+    /// The instant the [Graph] was created, which [Instant]s are rendered relative to
+    ///
+    /// [Graph]: crate::Graph
+    pub(crate) reference_instant: Option<Instant>,
+    /// Render the data inside of each [std::mem::MaybeUninit] as initialized, which is only set
+    /// by [Graph::set_maybe_uninits_assumed_initialized], whose caller vouches that this is safe
+    ///
+    /// [Graph::set_maybe_uninits_assumed_initialized]: crate::Graph::set_maybe_uninits_assumed_initialized
+    pub(crate) maybe_uninits_assumed_initialized: bool,
+    /// Label each edge with the label of the data it is drawn from, or its position among the
+    /// data owned by its owner
+    pub(crate) edge_labels: bool,
+    /// DOT attributes set on the edges a selector selects, overriding the default attributes of
+    /// their kinds
+    pub(crate) edge_attributes: Vec<(EdgeSelector, String, String)>,
+    /// The colors and fonts of table cells
+    pub(crate) theme: Theme,
+    /// Render the data raw pointers point to, which is only set by
+    /// [Graph::add_node_following_raw_pointers], whose caller vouches that this is safe
+    ///
+    /// [Graph::add_node_following_raw_pointers]: crate::Graph::add_node_following_raw_pointers
+    pub(crate) follow_raw_pointers: bool,
+}
+
+thread_local! {
+    /// The options of the [Graph] currently rendering nodes on this thread
+    ///
+    /// [Graph]: crate::Graph
+    static CURRENT: RefCell<Options> = RefCell::new(Options::default());
+}
+
+/// Restores the options that were current before [with] was called, even when unwinding
+struct Restore(Option<Options>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        if let Some(previous) = self.0.take() {
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+}
+
+/// Call f with options as the current options on this thread
+pub(crate) fn with<F, R>(options: &Options, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let previous = CURRENT.with(|current| current.replace(options.clone()));
+    let _restore = Restore(Some(previous));
+    f()
+}
+
+/// Get a copy of the current options on this thread
+pub(crate) fn current() -> Options {
+    CURRENT.with(|current| current.borrow().clone())
+}
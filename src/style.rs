@@ -0,0 +1,61 @@
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The look a type declares for itself, wherever its data appears in a [Graph]
+///
+/// This is returned by [Visualize::style], and can be set with the derive macro's
+/// `#[vizz(color = "...", shape = "...", icon = "...", title = "...")]` attribute on the type.
+///
+/// [Graph]: crate::Graph
+/// [Visualize::style]: crate::Visualize::style
+pub struct Style {
+    /// The background color of the cells of the data, which overrides the [Theme]
+    ///
+    /// [Theme]: crate::Theme
+    pub(crate) color: Option<String>,
+    /// The DOT shape drawn around the table of the data, when it is the root of a graph node
+    pub(crate) shape: Option<String>,
+    /// A glyph shown before the type of the data
+    pub(crate) icon: Option<String>,
+    /// Text shown in place of the type of the data
+    pub(crate) title: Option<String>,
+}
+
+impl Style {
+    /// Set the background color of the cells of the data
+    ///
+    /// Colors are DOT colors, like `"lightblue"` or `"#add8e6"`, see
+    /// <https://graphviz.org/docs/attr-types/color/>
+    pub fn with_color(self, color: impl Into<String>) -> Self {
+        Self {
+            color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Set the DOT shape drawn around the table of the data, like `"box"` or `"octagon"`, when it
+    /// is the root of a graph node
+    ///
+    /// See the DOT shape docs for the available shapes:
+    /// <https://graphviz.org/doc/info/shapes.html>
+    pub fn with_shape(self, shape: impl Into<String>) -> Self {
+        Self {
+            shape: Some(shape.into()),
+            ..self
+        }
+    }
+
+    /// Set a glyph shown before the type of the data, like `"⚠"`
+    pub fn with_icon(self, icon: impl Into<String>) -> Self {
+        Self {
+            icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    /// Set text shown in place of the type of the data
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use crate::util;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The cells of the table row rendered for a [DataDescription]
+///
+/// [DataDescription]: crate::DataDescription
+pub(crate) enum CellKind {
+    /// The memory address of the data
+    Address,
+    /// The type of the data
+    Type,
+    /// The label of the data, like a field name
+    Label,
+    /// The value of the data, and in memory layout mode where it sits in memory
+    Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The colors of a kind of table cell
+pub struct CellStyle {
+    /// The background color of the cell
+    fill: Option<String>,
+    /// The color of the text in the cell
+    font_color: Option<String>,
+}
+
+impl CellStyle {
+    /// Set the background color of the cell
+    ///
+    /// Colors are DOT colors, like `"lightblue"` or `"#add8e6"`, see
+    /// <https://graphviz.org/docs/attr-types/color/>
+    pub fn with_fill(self, color: impl Into<String>) -> Self {
+        Self {
+            fill: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Set the color of the text in the cell
+    pub fn with_font_color(self, color: impl Into<String>) -> Self {
+        Self {
+            font_color: Some(color.into()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The colors and fonts of a [Graph]
+///
+/// Start from one of the presets and change what's needed, then use it with [Graph::set_theme].
+///
+/// ```
+/// use vizz::{CellStyle, Graph, Theme};
+///
+/// let graph = Graph::new().set_theme(
+///     Theme::dark()
+///         .with_value_style(CellStyle::default().with_font_color("gold"))
+///         .with_type_color("Tree<_>", "#1f3a5f")
+///         .with_type_color("String", "#1f4f2f"),
+/// );
+/// ```
+///
+/// [Graph]: crate::Graph
+/// [Graph::set_theme]: crate::Graph::set_theme
+pub struct Theme {
+    /// The background color of the whole graph
+    background: Option<String>,
+    /// The font of all text
+    font_name: Option<String>,
+    /// The default color of all text
+    font_color: Option<String>,
+    /// The color of edges
+    edge_color: Option<String>,
+    /// The color of the border of each table cell
+    border_color: Option<String>,
+    /// The style of address cells
+    address: CellStyle,
+    /// The style of type cells
+    type_style: CellStyle,
+    /// The style of label cells
+    label: CellStyle,
+    /// The style of value cells
+    value: CellStyle,
+    /// The style of the cells of rows for memory holding no value, like spare capacity
+    unused: CellStyle,
+    /// Type name patterns and the fill color of the cells of data with a matching type, where the
+    /// first matching pattern wins
+    type_colors: Vec<(String, String)>,
+}
+
+impl Theme {
+    /// Black text on white, which is what a [Graph] looks like without a theme
+    ///
+    /// [Graph]: crate::Graph
+    pub fn light() -> Self {
+        Self {
+            background: None,
+            font_name: None,
+            font_color: None,
+            edge_color: None,
+            border_color: None,
+            address: CellStyle::default(),
+            type_style: CellStyle::default(),
+            label: CellStyle::default(),
+            value: CellStyle::default(),
+            unused: CellStyle::default().with_fill("lightgrey"),
+            type_colors: Vec::new(),
+        }
+    }
+
+    /// Light text on a dark background
+    pub fn dark() -> Self {
+        Self {
+            background: Some("#1e1e1e".into()),
+            font_color: Some("#d4d4d4".into()),
+            edge_color: Some("#d4d4d4".into()),
+            border_color: Some("#6e6e6e".into()),
+            address: CellStyle::default().with_font_color("#9cdcfe"),
+            type_style: CellStyle::default().with_font_color("#4ec9b0"),
+            label: CellStyle::default().with_font_color("#c586c0"),
+            value: CellStyle::default().with_font_color("#ce9178"),
+            unused: CellStyle::default()
+                .with_fill("#3c3c3c")
+                .with_font_color("#808080"),
+            ..Self::light()
+        }
+    }
+
+    /// Black on white only, with grey for unused memory, for printing
+    pub fn print() -> Self {
+        Self {
+            background: Some("white".into()),
+            font_color: Some("black".into()),
+            edge_color: Some("black".into()),
+            border_color: Some("black".into()),
+            unused: CellStyle::default().with_fill("grey90"),
+            ..Self::light()
+        }
+    }
+
+    /// Colors from the Okabe-Ito palette, which people with any kind of color blindness can tell
+    /// apart
+    pub fn color_blind_safe() -> Self {
+        Self {
+            address: CellStyle::default().with_font_color("#0072b2"),
+            type_style: CellStyle::default().with_font_color("#d55e00"),
+            label: CellStyle::default().with_font_color("#009e73"),
+            unused: CellStyle::default().with_fill("#e0e0e0"),
+            ..Self::light()
+        }
+    }
+
+    /// Set the background color of the whole graph
+    pub fn with_background(self, color: impl Into<String>) -> Self {
+        Self {
+            background: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Set the font of all text, like `"Helvetica"`
+    pub fn with_font_name(self, font_name: impl Into<String>) -> Self {
+        Self {
+            font_name: Some(font_name.into()),
+            ..self
+        }
+    }
+
+    /// Set the default color of all text
+    pub fn with_font_color(self, color: impl Into<String>) -> Self {
+        Self {
+            font_color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Set the color of edges, unless their kind or implementation sets another one
+    pub fn with_edge_color(self, color: impl Into<String>) -> Self {
+        Self {
+            edge_color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Set the color of the border of each table cell
+    pub fn with_border_color(self, color: impl Into<String>) -> Self {
+        Self {
+            border_color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Set the style of address cells
+    pub fn with_address_style(self, style: CellStyle) -> Self {
+        Self {
+            address: style,
+            ..self
+        }
+    }
+
+    /// Set the style of type cells
+    pub fn with_type_style(self, style: CellStyle) -> Self {
+        Self {
+            type_style: style,
+            ..self
+        }
+    }
+
+    /// Set the style of label cells
+    pub fn with_label_style(self, style: CellStyle) -> Self {
+        Self {
+            label: style,
+            ..self
+        }
+    }
+
+    /// Set the style of value cells
+    pub fn with_value_style(self, style: CellStyle) -> Self {
+        Self {
+            value: style,
+            ..self
+        }
+    }
+
+    /// Set the style of the cells of rows for memory holding no value, like spare capacity
+    pub fn with_unused_style(self, style: CellStyle) -> Self {
+        Self {
+            unused: style,
+            ..self
+        }
+    }
+
+    /// Fill the cells of all data with a type matching pattern with color
+    ///
+    /// A `_` in the pattern matches anything, so `Tree<_>` matches every `Tree`. Patterns without
+    /// a `::` are matched against type names without their module paths, so `String` matches
+    /// `alloc::string::String`. The first pattern added that matches wins.
+    pub fn with_type_color(mut self, pattern: impl Into<String>, color: impl Into<String>) -> Self {
+        self.type_colors.push((pattern.into(), color.into()));
+        self
+    }
+
+    /// The DOT attributes of the graph for this theme
+    pub(crate) fn graph_attributes(&self) -> Vec<(&'static str, &str)> {
+        self.background
+            .iter()
+            .map(|background| ("bgcolor", background.as_str()))
+            .chain(
+                self.font_name
+                    .iter()
+                    .map(|name| ("fontname", name.as_str())),
+            )
+            .chain(
+                self.font_color
+                    .iter()
+                    .map(|color| ("fontcolor", color.as_str())),
+            )
+            .collect()
+    }
+
+    /// The default DOT attributes of nodes for this theme
+    pub(crate) fn node_attributes(&self) -> Vec<(&'static str, &str)> {
+        self.font_name
+            .iter()
+            .map(|name| ("fontname", name.as_str()))
+            .chain(
+                self.font_color
+                    .iter()
+                    .map(|color| ("fontcolor", color.as_str())),
+            )
+            .collect()
+    }
+
+    /// The default DOT attributes of edges for this theme
+    pub(crate) fn edge_attributes(&self) -> Vec<(&'static str, &str)> {
+        self.edge_color
+            .iter()
+            .map(|color| ("color", color.as_str()))
+            .chain(
+                self.font_name
+                    .iter()
+                    .map(|name| ("fontname", name.as_str())),
+            )
+            .chain(
+                self.font_color
+                    .iter()
+                    .map(|color| ("fontcolor", color.as_str())),
+            )
+            .collect()
+    }
+
+    /// The style of a kind of cell in the row of data of type_string
+    fn cell_style(&self, cell: CellKind, type_string: &str, unused: bool) -> CellStyle {
+        let style = match (unused, cell) {
+            (true, _) => return self.unused.clone(),
+            (false, CellKind::Address) => &self.address,
+            (false, CellKind::Type) => &self.type_style,
+            (false, CellKind::Label) => &self.label,
+            (false, CellKind::Value) => &self.value,
+        };
+        match self
+            .type_colors
+            .iter()
+            .find(|(pattern, _)| type_name_matches(pattern, type_string))
+        {
+            Some((_, color)) => style.clone().with_fill(color.clone()),
+            None => style.clone(),
+        }
+    }
+
+    /// Render the attributes of a kind of table data (<TD>) in the row of data of type_string,
+    /// including the leading space
+    ///
+    /// The fill is the color the type of the data declares for itself, which overrides the theme,
+    /// except for unused data.
+    pub(crate) fn render_cell_attributes(
+        &self,
+        cell: CellKind,
+        type_string: &str,
+        unused: bool,
+        fill: Option<&str>,
+    ) -> String {
+        let style = self.cell_style(cell, type_string, unused);
+        let fill = match (unused, fill) {
+            (false, Some(fill)) => Some(fill.to_string()),
+            _ => style.fill,
+        }
+        .map(|fill| format!(r#" BGCOLOR="{}""#, util::html_attribute_encode(&fill)));
+        let border = self
+            .border_color
+            .as_ref()
+            .map(|color| format!(r#" COLOR="{}""#, util::html_attribute_encode(color)));
+        fill.into_iter().chain(border).collect()
+    }
+
+    /// Render the contents of a kind of table data (<TD>) in the row of data of type_string in the
+    /// font color of that cell
+    pub(crate) fn render_cell_contents(
+        &self,
+        cell: CellKind,
+        type_string: &str,
+        unused: bool,
+        contents: String,
+    ) -> String {
+        match self.cell_style(cell, type_string, unused).font_color {
+            Some(color) if !contents.is_empty() => format!(
+                r#"<FONT COLOR="{}">{}</FONT>"#,
+                util::html_attribute_encode(&color),
+                contents
+            ),
+            _ => contents,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// Whether a type name matches a pattern of [Theme::with_type_color]
+fn type_name_matches(pattern: &str, type_string: &str) -> bool {
+    let type_name = match pattern.contains("::") {
+        true => type_string.to_string(),
+        false => util::strip_module_paths(type_string),
+    };
+    glob_matches(&tokenize(pattern), &type_name)
+}
+
+/// A token of a type name pattern
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    /// Text which must match exactly
+    Literal(&'a str),
+    /// A `_` on its own, which matches anything
+    Wildcard,
+}
+
+/// Split a pattern into literal text and wildcards, where only a `_` which isn't part of an
+/// identifier is a wildcard
+fn tokenize(pattern: &str) -> Vec<Token<'_>> {
+    let is_identifier = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let mut tokens = Vec::new();
+    let mut literal_start = 0;
+    for (index, c) in pattern.char_indices() {
+        if c == '_'
+            && !is_identifier(pattern[..index].chars().next_back())
+            && !is_identifier(pattern[index + 1..].chars().next())
+        {
+            if literal_start < index {
+                tokens.push(Token::Literal(&pattern[literal_start..index]));
+            }
+            tokens.push(Token::Wildcard);
+            literal_start = index + 1;
+        }
+    }
+    if literal_start < pattern.len() {
+        tokens.push(Token::Literal(&pattern[literal_start..]));
+    }
+    tokens
+}
+
+/// Whether all of text matches the tokens
+fn glob_matches(tokens: &[Token], text: &str) -> bool {
+    match tokens.split_first() {
+        None => text.is_empty(),
+        Some((Token::Literal(literal), rest)) => text
+            .strip_prefix(literal)
+            .is_some_and(|text| glob_matches(rest, text)),
+        Some((Token::Wildcard, rest)) => text
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(text.len()))
+            .any(|index| glob_matches(rest, &text[index..])),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_type_name_matches() {
+        assert!(type_name_matches("String", "alloc::string::String"));
+        assert!(type_name_matches("Tree<_>", "my_crate::Tree<u8>"));
+        assert!(type_name_matches(
+            "Tree<_>",
+            "my_crate::Tree<alloc::vec::Vec<u8>>"
+        ));
+        assert!(type_name_matches("my_crate::_", "my_crate::Tree<u8>"));
+        assert!(type_name_matches("my_type", "crate::my_type"));
+        assert!(!type_name_matches("Tree<_>", "my_crate::Tree"));
+        assert!(!type_name_matches(
+            "String",
+            "alloc::vec::Vec<alloc::string::String>"
+        ));
+        assert!(!type_name_matches("my_type", "crate::my_typed"));
+    }
+
+    #[test]
+    fn test_render_cell() {
+        let theme = Theme::dark().with_type_color("u8", "navy");
+        assert_eq!(
+            theme.render_cell_attributes(CellKind::Value, "u8", false, None),
+            r##" BGCOLOR="navy" COLOR="#6e6e6e""##
+        );
+        assert_eq!(
+            theme.render_cell_contents(CellKind::Label, "u16", false, "x".into()),
+            r##"<FONT COLOR="#c586c0">x</FONT>"##
+        );
+        assert_eq!(
+            Theme::light().render_cell_attributes(CellKind::Label, "u8", true, Some("red")),
+            r#" BGCOLOR="lightgrey""#
+        );
+        assert_eq!(
+            theme.render_cell_attributes(CellKind::Type, "u8", false, Some("red")),
+            r##" BGCOLOR="red" COLOR="#6e6e6e""##
+        );
+        assert_eq!(
+            Theme::light().render_cell_contents(CellKind::Type, "u8", false, "u8".into()),
+            "u8"
+        );
+    }
+
+    #[test]
+    fn test_render_cell_escapes_quotes() {
+        let theme = Theme::light()
+            .with_type_color("u8", r#"red" BORDER="9"#)
+            .with_label_style(CellStyle::default().with_font_color(r#"<"blue">"#));
+        assert_eq!(
+            theme.render_cell_attributes(CellKind::Value, "u8", false, None),
+            r#" BGCOLOR="red&quot; BORDER=&quot;9""#
+        );
+        assert_eq!(
+            theme.render_cell_contents(CellKind::Label, "u8", false, "x".into()),
+            r#"<FONT COLOR="&lt;&quot;blue&quot;&gt;">x</FONT>"#
+        );
+    }
+}
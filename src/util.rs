@@ -1,9 +1,87 @@
+use crate::address::Address;
+use crate::data_description::{DataDescription, Value};
+use crate::options;
+
 pub fn html_encode(s: &str) -> String {
     s.replace("&", "&amp;")
         .replace("<", "&lt;")
         .replace(">", "&gt;")
 }
 
+/// Escape a string for use inside a double quoted attribute of an HTML-like label, like a color
+pub fn html_attribute_encode(s: &str) -> String {
+    html_encode(s).replace('"', "&quot;")
+}
+
+/// Escape a string for use inside a double quoted DOT ID, such as an attribute value
+pub fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Render DOT attribute key/value pairs as an attribute list, like `[key="value"]`
+pub fn render_attribute_list<'a>(attributes: impl Iterator<Item = (&'a str, &'a str)>) -> String {
+    format!(
+        "[{}]",
+        attributes
+            .map(|(key, value)| format!("{}=\"{}\"", key, dot_escape(value)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Remove the module paths from a type name, like `alloc::vec::Vec<alloc::string::String>` to
+/// `Vec<String>`
+pub fn strip_module_paths(type_string: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = type_string;
+    while let Some(index) = rest.find("::") {
+        stripped.push_str(&rest[..index]);
+        let segment_start = stripped
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(0, |index| index + 1);
+        stripped.truncate(segment_start);
+        rest = &rest[index + 2..];
+    }
+    stripped + rest
+}
+
+/// Whether every byte of a value of a type is always initialized, judging by its name
+///
+/// These are the primitives, [str] and pointers.
+pub fn is_plain_type(type_string: &str) -> bool {
+    const PLAIN_TYPES: &[&str] = &[
+        "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+        "i128", "isize", "f32", "f64", "str",
+    ];
+    const POINTER_PREFIXES: &[&str] = &["&", "*const ", "*mut "];
+
+    PLAIN_TYPES.contains(&type_string)
+        || POINTER_PREFIXES
+            .iter()
+            .any(|prefix| type_string.starts_with(prefix))
+}
+
+/// Render a row of a hex dump, with [None] for the bytes which weren't read
+pub fn render_hex_dump_row(bytes: &[Option<u8>]) -> String {
+    let hex = bytes
+        .iter()
+        .map(|byte| match byte {
+            Some(byte) => format!("{:02x}", byte),
+            None => "··".into(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let ascii: String = bytes
+        .iter()
+        .map(|byte| match byte {
+            Some(byte) if byte.is_ascii_graphic() || *byte == b' ' => *byte as char,
+            Some(_) => '.',
+            None => '·',
+        })
+        .collect();
+    format!("{} |{}|", hex, ascii)
+}
+
 /// Render a table, unless there would be no rows in that table, because that is a syntax error in
 /// DOT language
 pub fn render_table(table_rows: impl Iterator<Item = String>) -> String {
@@ -17,6 +95,113 @@ pub fn render_table(table_rows: impl Iterator<Item = String>) -> String {
     }
 }
 
-pub fn type_of<T>(_: &T) -> String {
+pub fn type_of<T>(_: &T) -> String
+where
+    T: ?Sized,
+{
     std::any::type_name::<T>().into()
 }
+
+/// Whether some values of T are invalid, so that an enum holding a T could store its tag in them
+pub fn has_niche<T>() -> bool {
+    size_of::<Option<T>>() == size_of::<T>()
+}
+
+/// Add rows describing the layout of an enum to the associated data of its current variant, if
+/// the current [Graph] renders enum layouts
+///
+/// This is used by the derive macro, which knows the discriminant of the current variant and the
+/// fields of every variant. variant_sizes holds the size of each variant's fields laid out on their
+/// own, without a tag, and tagged_variant_sizes the size of each variant's fields laid out with a
+/// tag, as in a tuple like `(u8, A, B)`. fields_have_niches is whether any field of any variant
+/// has a niche, see [has_niche]. repr_tag_size is the size of the tag when a `#[repr]` like
+/// `#[repr(u8)]` or `#[repr(C)]` guarantees that the tag is stored at the start of the enum.
+///
+/// An enum smaller than its largest tagged variant must store its tag in a niche, and one without
+/// any niches in its fields can't. Otherwise the compiler picks whichever layout leaves the larger
+/// niche, which can't be told from outside, so the niche is reported as unknown.
+///
+/// [Graph]: crate::Graph
+pub fn with_enum_layout<E>(
+    enumeration: &E,
+    associated_data: Option<Vec<DataDescription>>,
+    discriminant: i128,
+    variant_sizes: &[usize],
+    tagged_variant_sizes: &[usize],
+    fields_have_niches: bool,
+    repr_tag_size: Option<usize>,
+) -> Option<Vec<DataDescription>> {
+    if !options::current().enum_layouts {
+        return associated_data;
+    }
+
+    let address = Address::new(enumeration);
+    let size = size_of::<E>();
+    let largest_variant_size = variant_sizes.iter().copied().max().unwrap_or(0);
+    let largest_tagged_variant_size = tagged_variant_sizes.iter().copied().max().unwrap_or(0);
+    let niche = match (repr_tag_size, variant_sizes.len()) {
+        (Some(_), _) => "not in use",
+        (None, 0..=1) => "no tag",
+        (None, _) if size < largest_tagged_variant_size => "in use",
+        (None, _) if !fields_have_niches => "not in use",
+        (None, _) => "unknown",
+    };
+
+    let mut rows = associated_data.unwrap_or_default();
+    rows.push(DataDescription::computed(
+        &address,
+        "discriminant",
+        Value::Owned(discriminant.to_string()),
+    ));
+    rows.push(DataDescription::computed(
+        &address,
+        "size",
+        Value::Owned(format!(
+            "{} bytes, largest variant {} bytes",
+            size, largest_variant_size
+        )),
+    ));
+    rows.push(DataDescription::computed(
+        &address,
+        "niche",
+        Value::Owned(niche.into()),
+    ));
+
+    let tag_bytes = match repr_tag_size {
+        Some(tag_size) if tag_size <= size => {
+            let base: *const E = enumeration;
+            Some(
+                (0..tag_size)
+                    // SAFETY: the repr of the enum guarantees that its tag is stored at its start,
+                    // and the tag is always written, so its bytes are initialized
+                    .map(|offset| unsafe { *(base as *const u8).add(offset) })
+                    .collect::<Vec<_>>(),
+            )
+        }
+        // without any fields every byte of an enum is its tag, which holds the discriminant, so
+        // the tag bytes are known without reading memory that might be padding
+        None if niche == "not in use" && largest_variant_size == 0 && size <= 16 => {
+            let mut tag_bytes = discriminant.to_le_bytes()[..size].to_vec();
+            if cfg!(target_endian = "big") {
+                tag_bytes.reverse();
+            }
+            Some(tag_bytes)
+        }
+        _ => None,
+    };
+    if let Some(tag_bytes) = tag_bytes {
+        rows.push(DataDescription::computed(
+            &address,
+            "tag bytes",
+            Value::Owned(
+                tag_bytes
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+        ));
+    }
+
+    Some(rows)
+}
@@ -1,11 +1,12 @@
 use crate::data_description::{DataDescription, Value};
 use crate::node::RenderedNode;
+use crate::style::Style;
 
 /// A trait for defining how to visually represent a type
 ///
 /// For manual implementations, note that each of these methods has a default, so implementors need
 /// only implement the methods they need to modify.
-pub trait Visualize: Sized {
+pub trait Visualize {
     /// How to represent the data for this type, if at all
     ///
     /// # Primitive Example
@@ -119,6 +120,30 @@ pub trait Visualize: Sized {
         None
     }
 
+    /// The look of this data, wherever it appears in a [Graph]
+    ///
+    /// This makes data of a type recognizable at a glance, such as errors or tokens. The default
+    /// is no style of its own, so the data looks like the [Theme] says.
+    ///
+    /// ```
+    /// use vizz::Style;
+    /// use vizz::Visualize;
+    ///
+    /// struct Error;
+    ///
+    /// impl Visualize for Error {
+    ///     fn style(&self) -> Style {
+    ///         Style::default().with_color("salmon").with_icon("⚠")
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [Graph]: crate::Graph
+    /// [Theme]: crate::Theme
+    fn style(&self) -> Style {
+        Style::default()
+    }
+
     /// Render the node for this data
     ///
     /// N.B. that this node is useless on its own and must be put in the context of a [Graph]. End
@@ -127,7 +152,45 @@ pub trait Visualize: Sized {
     ///
     /// [Graph]: crate::Graph
     fn render_node(&self) -> RenderedNode {
-        RenderedNode::from(DataDescription::from(self))
+        RenderedNode::from(DataDescription::for_node(self))
+    }
+}
+
+/// An object safe companion to [Visualize], so that trait objects can be visualized
+///
+/// This is implemented for every type that implements [Visualize]. Make it a supertrait of a
+/// trait to be able to visualize that trait's objects, like a `Box<dyn Shape>`, which render as
+/// an edge to their concrete data.
+///
+/// ```
+/// use vizz::Graph;
+/// use vizz::VisualizeDyn;
+///
+/// trait Shape: VisualizeDyn {}
+///
+/// impl Shape for u8 {}
+///
+/// let shapes: Vec<Box<dyn Shape>> = vec![Box::new(5u8)];
+/// let dot = Graph::new().add_node(&shapes).render();
+/// ```
+pub trait VisualizeDyn {
+    /// The name of the concrete type of this data
+    fn type_name(&self) -> &'static str;
+
+    /// Create a [Value::Referenced] pointing at this data, as a pointer to it would
+    fn reference(&self) -> Value;
+}
+
+impl<T> VisualizeDyn for T
+where
+    T: ?Sized + Visualize,
+{
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn reference(&self) -> Value {
+        Value::reference_to(self)
     }
 }
 
@@ -139,6 +202,6 @@ mod test {
     fn test_render_node() {
         let target = 8u8;
         let target_address_string = crate::Address::new(&target);
-        assert_eq!((&target).render_node().inner(), format!("  node [shape=plaintext]\n    \"{0}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>u8</B></TD><TD PORT=\"{0}-value\">8</TD></TR></TABLE>>];\n    ", target_address_string))
+        assert_eq!(target.render_node().inner(), format!("    \"{0}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>u8</B></TD><TD PORT=\"{0}-value\">8</TD></TR></TABLE>>];\n    ", target_address_string))
     }
 }
@@ -47,6 +47,119 @@ fn test_enum() {
             .add_node(&enum_with_named_fields)
             .add_node(&enum_with_u8_and_string)
             .render(),
-            format!("digraph visualization {{\n  node [shape=plaintext]\n    \"{0}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{0}-value\">Plain</TD></TR></TABLE>>];\n      node [shape=plaintext]\n    \"{4}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{4}-address\"><I>{4}</I></TD><TD PORT=\"{4}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{4}-value\">WithU8</TD><TD PORT=\"{4}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{5}-address\"><I>{5}</I></TD><TD PORT=\"{5}-type\"><B>u8</B></TD><TD PORT=\"{5}-value\">10</TD></TR></TABLE></TD></TR></TABLE>>];\n      node [shape=plaintext]\n    \"{6}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{6}-address\"><I>{6}</I></TD><TD PORT=\"{6}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{6}-value\">WithStruct</TD><TD PORT=\"{6}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{7}-label\">my_u8</TD><TD PORT=\"{7}-address\"><I>{7}</I></TD><TD PORT=\"{7}-type\"><B>u8</B></TD><TD PORT=\"{7}-value\">8</TD></TR><TR><TD PORT=\"{8}-label\">my_string</TD><TD PORT=\"{8}-address\"><I>{8}</I></TD><TD PORT=\"{8}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{8}-value\">hey hey mic check 1 2 3</TD></TR></TABLE></TD></TR></TABLE>>];\n      node [shape=plaintext]\n    \"{1}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{1}-address\"><I>{1}</I></TD><TD PORT=\"{1}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{1}-value\">WithU8AndString</TD><TD PORT=\"{1}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{2}-address\"><I>{2}</I></TD><TD PORT=\"{2}-type\"><B>u8</B></TD><TD PORT=\"{2}-value\">6</TD></TR><TR><TD PORT=\"{3}-address\"><I>{3}</I></TD><TD PORT=\"{3}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{3}-value\">hey</TD></TR></TABLE></TD></TR></TABLE>>];\n    \n}}", plain_address, enum_with_u8_and_string_address, enum_with_u8_and_string_address_inner0, enum_with_u8_and_string_address_inner1, enum_with_u8_address, enum_with_u8_address_inner, enum_with_named_fields_address, enum_with_named_fields_u8_address, enum_with_named_fields_string_address)
+            format!("digraph visualization {{\n  node [shape=\"plaintext\"]\n    \"{0}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{0}-value\">Plain</TD></TR></TABLE>>];\n        \"{4}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{4}-address\"><I>{4}</I></TD><TD PORT=\"{4}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{4}-value\">WithU8</TD><TD PORT=\"{4}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{5}-address\"><I>{5}</I></TD><TD PORT=\"{5}-type\"><B>u8</B></TD><TD PORT=\"{5}-value\">10</TD></TR></TABLE></TD></TR></TABLE>>];\n        \"{6}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{6}-address\"><I>{6}</I></TD><TD PORT=\"{6}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{6}-value\">WithStruct</TD><TD PORT=\"{6}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{7}-label\">my_u8</TD><TD PORT=\"{7}-address\"><I>{7}</I></TD><TD PORT=\"{7}-type\"><B>u8</B></TD><TD PORT=\"{7}-value\">8</TD></TR><TR><TD PORT=\"{8}-label\">my_string</TD><TD PORT=\"{8}-address\"><I>{8}</I></TD><TD PORT=\"{8}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{8}-value\">hey hey mic check 1 2 3</TD></TR></TABLE></TD></TR></TABLE>>];\n        \"{1}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{1}-address\"><I>{1}</I></TD><TD PORT=\"{1}-type\"><B>enum::MyEnum</B></TD><TD PORT=\"{1}-value\">WithU8AndString</TD><TD PORT=\"{1}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{2}-address\"><I>{2}</I></TD><TD PORT=\"{2}-type\"><B>u8</B></TD><TD PORT=\"{2}-value\">6</TD></TR><TR><TD PORT=\"{3}-address\"><I>{3}</I></TD><TD PORT=\"{3}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{3}-value\">hey</TD></TR></TABLE></TD></TR></TABLE>>];\n    \n}}", plain_address, enum_with_u8_and_string_address, enum_with_u8_and_string_address_inner0, enum_with_u8_and_string_address_inner1, enum_with_u8_address, enum_with_u8_address_inner, enum_with_named_fields_address, enum_with_named_fields_u8_address, enum_with_named_fields_string_address)
     );
 }
+
+#[allow(dead_code)]
+#[derive(Visualize)]
+enum MaybeBoxed {
+    Nothing,
+    Boxed(Box<u8>),
+}
+
+#[allow(dead_code)]
+#[derive(Visualize)]
+enum Padded {
+    Pair(u8, u32),
+    Nothing,
+}
+
+#[allow(dead_code)]
+#[derive(Visualize)]
+enum Tied {
+    Pair(u8, char),
+    Nothing,
+}
+
+#[allow(dead_code)]
+#[derive(Visualize)]
+#[repr(u32)]
+enum Wide {
+    Pair(u8, u32),
+    Nothing = 4,
+}
+
+#[allow(dead_code)]
+#[derive(Visualize)]
+enum Color {
+    Red = 3,
+    Green,
+}
+
+#[test]
+fn test_enum_layouts() {
+    let boxed = MaybeBoxed::Boxed(Box::new(1));
+    let boxed_address = vizz::Address::new(&boxed);
+    let rendered = Graph::new()
+        .set_enum_layouts(true)
+        .add_node(&boxed)
+        .render();
+    assert!(rendered.contains(&format!("<TD PORT=\"{0}-discriminant-label\">discriminant</TD><TD PORT=\"{0}-discriminant-value\">1</TD>", boxed_address)));
+    assert!(rendered.contains(&format!("<TD PORT=\"{0}-size-label\">size</TD><TD PORT=\"{0}-size-value\">{1} bytes, largest variant {1} bytes</TD>", boxed_address, std::mem::size_of::<usize>())));
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-niche-label\">niche</TD><TD PORT=\"{0}-niche-value\">in use</TD>",
+        boxed_address
+    )));
+    assert!(!rendered.contains("tag bytes"));
+
+    let green = Color::Green;
+    let green_address = vizz::Address::new(&green);
+    let rendered = Graph::new()
+        .set_enum_layouts(true)
+        .add_node(&green)
+        .render();
+    assert!(rendered.contains(&format!("<TD PORT=\"{0}-discriminant-label\">discriminant</TD><TD PORT=\"{0}-discriminant-value\">4</TD>", green_address)));
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-niche-label\">niche</TD><TD PORT=\"{0}-niche-value\">not in use</TD>",
+        green_address
+    )));
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-tag bytes-label\">tag bytes</TD><TD PORT=\"{0}-tag bytes-value\">04</TD>",
+        green_address
+    )));
+
+    // the tag fits in the padding of the largest variant, without using a niche
+    let pair = Padded::Pair(1, 2);
+    let pair_address = vizz::Address::new(&pair);
+    let rendered = Graph::new().set_enum_layouts(true).add_node(&pair).render();
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-niche-label\">niche</TD><TD PORT=\"{0}-niche-value\">not in use</TD>",
+        pair_address
+    )));
+    // without a repr the tag could be anywhere, so its bytes aren't read
+    assert!(!rendered.contains("tag bytes"));
+
+    // the tag could fit in the padding of the largest variant or in a niche of the char, and
+    // which one the compiler picks can't be told from the sizes
+    let tied = Tied::Nothing;
+    let tied_address = vizz::Address::new(&tied);
+    let rendered = Graph::new().set_enum_layouts(true).add_node(&tied).render();
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-niche-label\">niche</TD><TD PORT=\"{0}-niche-value\">unknown</TD>",
+        tied_address
+    )));
+    assert!(!rendered.contains("tag bytes"));
+
+    // the repr sets the type of the tag and puts it at the start
+    let wide = Wide::Nothing;
+    let wide_address = vizz::Address::new(&wide);
+    let rendered = Graph::new().set_enum_layouts(true).add_node(&wide).render();
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-niche-label\">niche</TD><TD PORT=\"{0}-niche-value\">not in use</TD>",
+        wide_address
+    )));
+    let tag_bytes = 4u32
+        .to_ne_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-tag bytes-label\">tag bytes</TD><TD PORT=\"{0}-tag bytes-value\">{1}</TD>",
+        wide_address, tag_bytes
+    )));
+
+    // without the option, derived enums render as before
+    assert!(!Graph::from(&green).render().contains("discriminant"));
+}
@@ -21,6 +21,53 @@ fn test_struct() {
     let u8_address = vizz::Address::new(&my_struct.my_u8);
     let string_address = vizz::Address::new(&my_struct.my_string);
     let ref_address = vizz::Address::new(&my_struct.my_ref);
+    // the rendering of lifetime parameters in type names varies between compiler versions
+    let struct_type = vizz::util::html_encode(&vizz::util::type_of(&my_struct));
 
-    assert_eq!(Graph::new().add_node(&my_struct).render(), format!("digraph visualization {{\n  node [shape=plaintext]\n    \"{0}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>struct::MyStruct</B></TD><TD PORT=\"{0}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{2}-label\">my_u8</TD><TD PORT=\"{2}-address\"><I>{2}</I></TD><TD PORT=\"{2}-type\"><B>u8</B></TD><TD PORT=\"{2}-value\">42</TD></TR><TR><TD PORT=\"{3}-label\">my_string</TD><TD PORT=\"{3}-address\"><I>{3}</I></TD><TD PORT=\"{3}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{3}-value\">HELLO WORLD</TD></TR><TR><TD PORT=\"{4}-label\">my_ref</TD><TD PORT=\"{4}-address\"><I>{4}</I></TD><TD PORT=\"{4}-type\"><B>&amp;alloc::string::String</B></TD><TD PORT=\"{4}-value\"></TD></TR></TABLE></TD></TR></TABLE>>];\n    \"{0}\":\"{4}-value\" -> \"{1}\":\"{1}-address\"\n  node [shape=plaintext]\n    \"{5}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{5}-address\"><I>{5}</I></TD><TD PORT=\"{5}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{5}-value\">yabadabadoo!</TD></TR></TABLE>>];\n    \n\n}}", struct_address, ref_target_address, u8_address, string_address, ref_address, ref_target_address));
+    assert_eq!(Graph::new().add_node(&my_struct).render(), format!("digraph visualization {{\n  node [shape=\"plaintext\"]\n    \"{0}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{0}-address\"><I>{0}</I></TD><TD PORT=\"{0}-type\"><B>{6}</B></TD><TD PORT=\"{0}-associated-data\"><TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{2}-label\">my_u8</TD><TD PORT=\"{2}-address\"><I>{2}</I></TD><TD PORT=\"{2}-type\"><B>u8</B></TD><TD PORT=\"{2}-value\">42</TD></TR><TR><TD PORT=\"{3}-label\">my_string</TD><TD PORT=\"{3}-address\"><I>{3}</I></TD><TD PORT=\"{3}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{3}-value\">HELLO WORLD</TD></TR><TR><TD PORT=\"{4}-label\">my_ref</TD><TD PORT=\"{4}-address\"><I>{4}</I></TD><TD PORT=\"{4}-type\"><B>&amp;alloc::string::String</B></TD><TD PORT=\"{4}-value\"></TD></TR></TABLE></TD></TR></TABLE>>];\n    \"{0}\":\"{4}-value\" -> \"{1}\":\"{1}-address\"\n    \"{5}\" [label=<<TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\"><TR><TD PORT=\"{5}-address\"><I>{5}</I></TD><TD PORT=\"{5}-type\"><B>alloc::string::String</B></TD><TD PORT=\"{5}-value\">yabadabadoo!</TD></TR></TABLE>>];\n    \n\n}}", struct_address, ref_target_address, u8_address, string_address, ref_address, ref_target_address, struct_type));
+}
+
+#[derive(Visualize)]
+struct WithHexField {
+    #[vizz(hex)]
+    magic: u16,
+    length: u16,
+}
+
+#[test]
+fn test_hex_field_attribute() {
+    let with_hex_field = WithHexField {
+        magic: u16::from_ne_bytes([0xca, 0xfe]),
+        length: 3,
+    };
+    let magic_address = vizz::Address::new(&with_hex_field.magic);
+
+    let rendered = Graph::from(&with_hex_field).render();
+    assert!(rendered.contains(&format!(
+        "<TD PORT=\"{0}-hex +0-label\">hex +0</TD><TD PORT=\"{0}-hex +0-value\">ca fe |..|</TD>",
+        magic_address
+    )));
+    assert_eq!(rendered.matches("hex +0-label").count(), 1);
+}
+
+#[derive(Visualize)]
+#[vizz(color = "salmon", shape = "octagon")]
+#[vizz(icon = "⚠", title = "Error")]
+struct StyledError {
+    code: u8,
+}
+
+#[test]
+fn test_style_type_attribute() {
+    let styled_error = StyledError { code: 7 };
+    let error_address = vizz::Address::new(&styled_error);
+    let code_address = vizz::Address::new(&styled_error.code);
+
+    let rendered = Graph::from(&styled_error).render();
+    assert!(rendered.contains(&format!(
+        "<TR><TD PORT=\"{0}-address\" BGCOLOR=\"salmon\"><I>{0}</I></TD><TD PORT=\"{0}-type\" BGCOLOR=\"salmon\">⚠ <B>Error</B></TD>",
+        error_address
+    )));
+    assert!(rendered.contains(&format!("<TD PORT=\"{0}-value\">7</TD>", code_address)));
+    assert!(rendered.contains("</TABLE>>, shape=\"octagon\"];"));
 }
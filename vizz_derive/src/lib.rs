@@ -2,10 +2,14 @@ use proc_macro::TokenStream;
 use quote::format_ident;
 use quote::quote;
 use syn::Data;
+use syn::Field;
 use syn::Fields;
 use syn::Index;
+use syn::Lit;
+use syn::Meta;
+use syn::NestedMeta;
 
-#[proc_macro_derive(Visualize)]
+#[proc_macro_derive(Visualize, attributes(vizz))]
 pub fn visualize_derive(input: TokenStream) -> TokenStream {
     // Construct a Rust code ast we can manipulate
     let ast = syn::parse(input).unwrap();
@@ -14,6 +18,104 @@ pub fn visualize_derive(input: TokenStream) -> TokenStream {
     impl_visualize(&ast)
 }
 
+/// Create the method calls a field's `#[vizz(...)]` attributes add to its DataDescription, which
+/// is built from the expression `field`
+fn field_attribute_calls(
+    field: &Field,
+    field_expr: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut calls = Vec::new();
+
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("vizz")) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            _ => panic!("expected #[vizz(...)]"),
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hex") => {
+                    calls.push(quote! { .with_hex_dump(#field_expr) });
+                }
+                _ => panic!("unsupported field attribute, expected #[vizz(hex)]"),
+            }
+        }
+    }
+
+    quote! { #(#calls)* }
+}
+
+/// Create the `style` method from the type's `#[vizz(...)]` attributes, or nothing if it has
+/// none, leaving the default
+fn style_fn_impl(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let mut calls = Vec::new();
+
+    for attr in ast.attrs.iter().filter(|attr| attr.path.is_ident("vizz")) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            _ => panic!("expected #[vizz(...)]"),
+        };
+        for nested in list.nested {
+            let (key, value) = match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value)) => match name_value.lit {
+                    Lit::Str(value) => (name_value.path, value),
+                    _ => panic!("expected a string, like #[vizz(color = \"red\")]"),
+                },
+                _ => panic!("expected a key and value, like #[vizz(color = \"red\")]"),
+            };
+            let method = match key.get_ident().map(|ident| ident.to_string()).as_deref() {
+                Some("color") => format_ident!("with_color"),
+                Some("shape") => format_ident!("with_shape"),
+                Some("icon") => format_ident!("with_icon"),
+                Some("title") => format_ident!("with_title"),
+                _ => panic!(
+                    "unsupported type attribute, expected #[vizz(...)] with color, shape, icon or title"
+                ),
+            };
+            calls.push(quote! { .#method(#value) });
+        }
+    }
+
+    if calls.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        fn style(&self) -> ::vizz::Style {
+            ::vizz::Style::default() #(#calls)*
+        }
+    }
+}
+
+/// The type of the tag of an enum from its `#[repr(...)]` attributes, if they guarantee that the
+/// tag is stored at the start of the enum, as the primitive representations and `#[repr(C)]` do
+fn repr_tag(ast: &syn::DeriveInput) -> Option<proc_macro2::TokenStream> {
+    const INTEGERS: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    let mut c = false;
+    for attr in ast.attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            _ => continue,
+        };
+        for nested in list.nested {
+            if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                match path.get_ident() {
+                    Some(ident) if INTEGERS.contains(&ident.to_string().as_str()) => {
+                        return Some(quote! { #ident });
+                    }
+                    Some(ident) if ident == "C" => c = true,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // a C enum is the size of a C int, unless its discriminants don't fit
+    c.then(|| quote! { ::std::os::raw::c_int })
+}
+
 fn impl_visualize(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
 
@@ -63,13 +165,17 @@ fn impl_visualize(ast: &syn::DeriveInput) -> TokenStream {
                     .for_each(|(field_num, field)| match &field.ident {
                         Some(ident) => {
                             let label = format!("{}", ident);
+                            let attribute_calls =
+                                field_attribute_calls(field, &quote! { &self.#ident });
                             members.push(
-                                quote! { ::vizz::DataDescription::from(&self.#ident).with_label(#label) },
+                                quote! { ::vizz::DataDescription::from(&self.#ident).with_label(#label)#attribute_calls },
                             );
                         }
                         None => {
                             let ident = Index::from(field_num);
-                            members.push(quote! { ::vizz::DataDescription::from(&self.#ident) });
+                            let attribute_calls =
+                                field_attribute_calls(field, &quote! { &self.#ident });
+                            members.push(quote! { ::vizz::DataDescription::from(&self.#ident)#attribute_calls });
                         }
                     });
 
@@ -94,8 +200,10 @@ fn impl_visualize(ast: &syn::DeriveInput) -> TokenStream {
                                     .expect("named fields should have idents");
 
                                 let label = format!("{}", ident);
+                                let attribute_calls =
+                                    field_attribute_calls(field, &quote! { #ident });
                                 members.push(
-                                        quote! { ::vizz::DataDescription::from(#ident).with_label(#label) },
+                                        quote! { ::vizz::DataDescription::from(#ident).with_label(#label)#attribute_calls },
                                     );
                                 param_names.push(quote! { #ident });
                             }
@@ -112,7 +220,9 @@ fn impl_visualize(ast: &syn::DeriveInput) -> TokenStream {
                                         "__VISUALIZE_PARAM_{}",
                                         field_num
                                     ));
-                                    members.push(quote! { ::vizz::DataDescription::from(#ident) });
+                                    let attribute_calls =
+                                        field_attribute_calls(field, &quote! { #ident });
+                                    members.push(quote! { ::vizz::DataDescription::from(#ident)#attribute_calls });
                                     param_names.push(quote! { #ident });
                                 });
                             quote! { ( #(#param_names),* ) }
@@ -129,7 +239,72 @@ fn impl_visualize(ast: &syn::DeriveInput) -> TokenStream {
                     arms.push(quote! { #name::#ident #params => #match_result });
                 }
 
-                quote! { match self { #(#arms),* } }
+                // the tag the repr sets, or the smallest which holds a discriminant for every
+                // variant
+                let repr_tag = repr_tag(ast);
+                let tag = match (&repr_tag, enum_decl.variants.len()) {
+                    (Some(repr_tag), _) => repr_tag.clone(),
+                    (None, 0..=256) => quote! { u8 },
+                    (None, _) => quote! { u16 },
+                };
+                let repr_tag_size = match &repr_tag {
+                    Some(repr_tag) => quote! {
+                        ::std::option::Option::Some(::std::mem::size_of::<#repr_tag>())
+                    },
+                    None => quote! { ::std::option::Option::None },
+                };
+                let all_field_types = enum_decl
+                    .variants
+                    .iter()
+                    .flat_map(|variant| variant.fields.iter().map(|field| &field.ty));
+
+                // the discriminant of variants without an explicit one follows the last explicit
+                // one, or counts from zero
+                let mut discriminant_arms = Vec::new();
+                let mut variant_sizes = Vec::new();
+                let mut tagged_variant_sizes = Vec::new();
+                let mut last_explicit_discriminant = None;
+                let mut implicit_offset = 0;
+
+                for variant in &enum_decl.variants {
+                    let ident = &variant.ident;
+
+                    if let Some((_, discriminant)) = &variant.discriminant {
+                        last_explicit_discriminant = Some(discriminant);
+                        implicit_offset = 0;
+                    }
+                    let offset = Index::from(implicit_offset);
+                    let discriminant = match last_explicit_discriminant {
+                        Some(discriminant) => quote! { (#discriminant) as i128 + #offset },
+                        None => quote! { #offset },
+                    };
+                    implicit_offset += 1;
+
+                    let params = match variant.fields {
+                        Fields::Unit => quote! {},
+                        Fields::Unnamed(..) => quote! { (..) },
+                        Fields::Named(..) => quote! { {..} },
+                    };
+                    discriminant_arms.push(quote! { #name::#ident #params => #discriminant });
+
+                    let field_types: Vec<_> =
+                        variant.fields.iter().map(|field| &field.ty).collect();
+                    variant_sizes.push(quote! { ::std::mem::size_of::<( #(#field_types,)* )>() });
+                    tagged_variant_sizes
+                        .push(quote! { ::std::mem::size_of::<( #tag, #(#field_types,)* )>() });
+                }
+
+                quote! {
+                    ::vizz::util::with_enum_layout(
+                        self,
+                        match self { #(#arms),* },
+                        match self { #(#discriminant_arms),* },
+                        &[ #(#variant_sizes),* ],
+                        &[ #(#tagged_variant_sizes),* ],
+                        false #(|| ::vizz::util::has_niche::<#all_field_types>())*,
+                        #repr_tag_size,
+                    )
+                }
             }
             Data::Union(_) => panic!(
                 "Unions are not yet supported, see https://github.com/theryangeary/vizz/issues/1"
@@ -143,12 +318,15 @@ fn impl_visualize(ast: &syn::DeriveInput) -> TokenStream {
         }
     };
 
+    let style_fn_impl = style_fn_impl(ast);
+
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
 
     let impl_block = quote! {
         impl #impl_generics ::vizz::Visualize for #name #ty_generics #where_clause {
             #data_fn_impl
             #associated_data_fn_impl
+            #style_fn_impl
         }
     };
 